@@ -7,11 +7,34 @@ extern crate cmake;
 use std::env;
 
 fn main() {
-    let dst = cmake::Config::new(".").build();
+    // Each optional image codec is a cargo feature that builds Skia's wrapper
+    // around the system library of the same name.
+    let codecs = [
+        ("GIF", &["gif"][..]),
+        ("JPEG", &["jpeg"][..]),
+        ("PNG", &["png", "z"][..]),
+        ("WEBP", &["webp"][..]),
+    ];
+
+    let mut config = cmake::Config::new(".");
+    for &(codec, _) in &codecs {
+        if env::var_os(format!("CARGO_FEATURE_{}", codec)).is_some() {
+            config.define(format!("SKIA_ENABLE_{}", codec), "ON");
+        }
+    }
+    let dst = config.build();
     println!("cargo:rustc-link-search=native={}/lib", dst.display());
     println!("cargo:rustc-link-lib=static=skia");
     println!("cargo:outdir={}", dst.display());
 
+    for &(codec, libs) in &codecs {
+        if env::var_os(format!("CARGO_FEATURE_{}", codec)).is_some() {
+            for lib in libs {
+                println!("cargo:rustc-link-lib={}", lib);
+            }
+        }
+    }
+
     let target = env::var("TARGET").unwrap();
     if target.contains("unknown-linux-gnu") {
         println!("cargo:rustc-link-lib=stdc++");
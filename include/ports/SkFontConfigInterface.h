@@ -106,6 +106,23 @@ public:
                                 SkTArray<FontIdentity>*) {
         return false;
     }
+
+    /**
+     *  Find a font with a glyph for character, preferring familyName (which
+     *  may be NULL), faces close to style, and fonts for the bpc47 language
+     *  (which may be NULL or empty).
+     *
+     *  If a match is found, return true and set its outFontIdentifier.
+     *      If outFamilyName is not null, assign the found familyName to it.
+     *
+     *  If a match is not found, return false, and ignore all out parameters.
+     */
+    virtual bool matchCharacter(const char familyName[], const SkFontStyle& style,
+                                const char bpc47[], uint32_t character,
+                                FontIdentity* outFontIdentifier,
+                                SkString* outFamilyName) {
+        return false;
+    }
     typedef SkRefCnt INHERITED;
 };
 
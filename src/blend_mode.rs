@@ -0,0 +1,228 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use color::Color;
+use skia;
+
+/// How a source colour is combined with the destination, matching
+/// `SkXfermode::Mode`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    // The Porter-Duff modes.
+    Clear = 0,
+    Src = 1,
+    Dst = 2,
+    #[default]
+    SrcOver = 3,
+    DstOver = 4,
+    SrcIn = 5,
+    DstIn = 6,
+    SrcOut = 7,
+    DstOut = 8,
+    SrcATop = 9,
+    DstATop = 10,
+    Xor = 11,
+    Plus = 12,
+    Modulate = 13,
+
+    // The separable modes of CSS Compositing and Blending.
+    Screen = 14,
+    Overlay = 15,
+    Darken = 16,
+    Lighten = 17,
+    ColorDodge = 18,
+    ColorBurn = 19,
+    HardLight = 20,
+    SoftLight = 21,
+    Difference = 22,
+    Exclusion = 23,
+    Multiply = 24,
+
+    // The non-separable modes of CSS Compositing and Blending.
+    Hue = 25,
+    Saturation = 26,
+    Color = 27,
+    Luminosity = 28,
+}
+
+impl BlendMode {
+    /// Parses a CSS `mix-blend-mode` or `background-blend-mode` value.
+    pub fn from_mix_blend_mode(name: &str) -> Option<BlendMode> {
+        match name {
+            "normal" => Some(BlendMode::SrcOver),
+            "plus-lighter" => Some(BlendMode::Plus),
+            _ => BlendMode::from_blend_name(name),
+        }
+    }
+
+    /// Parses a canvas `globalCompositeOperation` value.
+    pub fn from_composite_operation(name: &str) -> Option<BlendMode> {
+        match name {
+            "clear" => Some(BlendMode::Clear),
+            "copy" => Some(BlendMode::Src),
+            "source-over" => Some(BlendMode::SrcOver),
+            "destination-over" => Some(BlendMode::DstOver),
+            "source-in" => Some(BlendMode::SrcIn),
+            "destination-in" => Some(BlendMode::DstIn),
+            "source-out" => Some(BlendMode::SrcOut),
+            "destination-out" => Some(BlendMode::DstOut),
+            "source-atop" => Some(BlendMode::SrcATop),
+            "destination-atop" => Some(BlendMode::DstATop),
+            "xor" => Some(BlendMode::Xor),
+            "lighter" => Some(BlendMode::Plus),
+            _ => BlendMode::from_blend_name(name),
+        }
+    }
+
+    /// The canvas `globalCompositeOperation` name of this mode. `Dst` and
+    /// `Modulate` have none.
+    pub fn composite_operation(self) -> Option<&'static str> {
+        Some(match self {
+            BlendMode::Clear => "clear",
+            BlendMode::Src => "copy",
+            BlendMode::SrcOver => "source-over",
+            BlendMode::DstOver => "destination-over",
+            BlendMode::SrcIn => "source-in",
+            BlendMode::DstIn => "destination-in",
+            BlendMode::SrcOut => "source-out",
+            BlendMode::DstOut => "destination-out",
+            BlendMode::SrcATop => "source-atop",
+            BlendMode::DstATop => "destination-atop",
+            BlendMode::Xor => "xor",
+            BlendMode::Plus => "lighter",
+            BlendMode::Dst | BlendMode::Modulate => return None,
+            BlendMode::Screen => "screen",
+            BlendMode::Overlay => "overlay",
+            BlendMode::Darken => "darken",
+            BlendMode::Lighten => "lighten",
+            BlendMode::ColorDodge => "color-dodge",
+            BlendMode::ColorBurn => "color-burn",
+            BlendMode::HardLight => "hard-light",
+            BlendMode::SoftLight => "soft-light",
+            BlendMode::Difference => "difference",
+            BlendMode::Exclusion => "exclusion",
+            BlendMode::Multiply => "multiply",
+            BlendMode::Hue => "hue",
+            BlendMode::Saturation => "saturation",
+            BlendMode::Color => "color",
+            BlendMode::Luminosity => "luminosity",
+        })
+    }
+
+    /// The blend modes, whose names CSS and canvas share.
+    fn from_blend_name(name: &str) -> Option<BlendMode> {
+        match name {
+            "multiply" => Some(BlendMode::Multiply),
+            "screen" => Some(BlendMode::Screen),
+            "overlay" => Some(BlendMode::Overlay),
+            "darken" => Some(BlendMode::Darken),
+            "lighten" => Some(BlendMode::Lighten),
+            "color-dodge" => Some(BlendMode::ColorDodge),
+            "color-burn" => Some(BlendMode::ColorBurn),
+            "hard-light" => Some(BlendMode::HardLight),
+            "soft-light" => Some(BlendMode::SoftLight),
+            "difference" => Some(BlendMode::Difference),
+            "exclusion" => Some(BlendMode::Exclusion),
+            "hue" => Some(BlendMode::Hue),
+            "saturation" => Some(BlendMode::Saturation),
+            "color" => Some(BlendMode::Color),
+            "luminosity" => Some(BlendMode::Luminosity),
+            _ => None,
+        }
+    }
+}
+
+/// Which destination pixels `Xfermode::avoid` draws on, matching
+/// `SkAvoidXfermode::Mode`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvoidMode {
+    /// Everywhere except on the colour.
+    AvoidColor = 0,
+    /// Only on the colour.
+    TargetColor = 1,
+}
+
+/// A reference counted `SkXfermode` for the transfer functions that do not
+/// fit a `BlendMode`.
+///
+/// The GPU backend draws any mode for which `is_supported_on_gpu` is false
+/// as `SrcOver`, so surfaces that use them should be raster.
+pub struct Xfermode {
+    xfermode: skia::SkiaSkXfermodeRef,
+}
+
+impl Drop for Xfermode {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkXfermodeRelease(self.xfermode);
+        }
+    }
+}
+
+impl Clone for Xfermode {
+    fn clone(&self) -> Xfermode {
+        unsafe {
+            skia::SkiaSkXfermodeRetain(self.xfermode);
+        }
+        Xfermode {
+            xfermode: self.xfermode,
+        }
+    }
+}
+
+// Xfermodes are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for Xfermode {}
+unsafe impl Sync for Xfermode {}
+
+impl Xfermode {
+    fn from_raw(xfermode: skia::SkiaSkXfermodeRef) -> Option<Xfermode> {
+        if xfermode.is_null() {
+            return None;
+        }
+        Some(Xfermode { xfermode })
+    }
+
+    /// SVG `feComposite operator="arithmetic"`: each channel becomes
+    /// `k[0] * src * dst + k[1] * src + k[2] * dst + k[3]`, clamped, on
+    /// premultiplied values from 0 to 1, as SVG specifies. With
+    /// `enforce_premul` the colour channels are also clamped to the alpha.
+    /// Works on the GPU.
+    pub fn arithmetic(k: [f32; 4], enforce_premul: bool) -> Option<Xfermode> {
+        Xfermode::from_raw(unsafe {
+            skia::SkiaSkXfermodeCreateArithmetic(k[0], k[1], k[2], k[3], enforce_premul)
+        })
+    }
+
+    /// Mixes `scale` of the source with `1 - scale` of the destination.
+    /// Raster only, except at 0 and 1.
+    pub fn lerp(scale: f32) -> Option<Xfermode> {
+        Xfermode::from_raw(unsafe { skia::SkiaSkXfermodeCreateLerp(scale) })
+    }
+
+    /// Draws only where the destination is, or is not, close to `color`.
+    /// A higher `tolerance` avoids fewer colours or targets more. Raster
+    /// only.
+    pub fn avoid(color: Color, tolerance: u8, mode: AvoidMode) -> Option<Xfermode> {
+        Xfermode::from_raw(unsafe {
+            skia::SkiaSkXfermodeCreateAvoid(color.0, tolerance, mode as i32)
+        })
+    }
+
+    /// XORs the source pixels and `color` into the destination, leaving its
+    /// alpha opaque. Raster only.
+    pub fn pixel_xor(color: Color) -> Xfermode {
+        Xfermode::from_raw(unsafe { skia::SkiaSkXfermodeCreatePixelXor(color.0) })
+            .expect("SkPixelXorXfermode::Create failed")
+    }
+
+    pub fn is_supported_on_gpu(&self) -> bool {
+        unsafe { skia::SkiaSkXfermodeIsSupportedOnGpu(self.xfermode) }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkXfermodeRef {
+        self.xfermode
+    }
+}
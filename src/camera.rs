@@ -0,0 +1,163 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use canvas::Canvas;
+use matrix::Matrix;
+use skia;
+
+use euclid::default::{Point3D, Vector3D};
+
+/// A stack of 3D rotations and translations, viewed through Skia's default
+/// camera, like `Sk3DView`.
+pub struct View3D {
+    view: skia::Skia3DViewRef,
+}
+
+impl Drop for View3D {
+    fn drop(&mut self) {
+        unsafe {
+            skia::Skia3DViewDelete(self.view);
+        }
+    }
+}
+
+unsafe impl Send for View3D {}
+
+impl Default for View3D {
+    fn default() -> View3D {
+        View3D::new()
+    }
+}
+
+impl View3D {
+    pub fn new() -> View3D {
+        View3D {
+            view: unsafe { skia::Skia3DViewCreate() },
+        }
+    }
+
+    pub fn save(&mut self) {
+        unsafe {
+            skia::Skia3DViewSave(self.view);
+        }
+    }
+
+    pub fn restore(&mut self) {
+        unsafe {
+            skia::Skia3DViewRestore(self.view);
+        }
+    }
+
+    pub fn translate(&mut self, x: f32, y: f32, z: f32) {
+        unsafe {
+            skia::Skia3DViewTranslate(self.view, x, y, z);
+        }
+    }
+
+    pub fn rotate_x(&mut self, degrees: f32) {
+        unsafe {
+            skia::Skia3DViewRotateX(self.view, degrees);
+        }
+    }
+
+    pub fn rotate_y(&mut self, degrees: f32) {
+        unsafe {
+            skia::Skia3DViewRotateY(self.view, degrees);
+        }
+    }
+
+    pub fn rotate_z(&mut self, degrees: f32) {
+        unsafe {
+            skia::Skia3DViewRotateZ(self.view, degrees);
+        }
+    }
+
+    /// The perspective matrix that projects the current transform.
+    pub fn matrix(&self) -> Matrix {
+        let mut result = skia::SkiaMatrix { values: [0.0; 9] };
+        unsafe {
+            skia::Skia3DViewGetMatrix(self.view, &mut result);
+        }
+        Matrix::from_values(result.values)
+    }
+
+    /// Concatenates `matrix()` onto the canvas' current matrix.
+    pub fn apply_to_canvas(&self, canvas: &mut Canvas) {
+        unsafe {
+            skia::Skia3DViewApplyToCanvas(self.view, canvas.as_raw());
+        }
+    }
+
+    /// The dot product of a unit vector with the transformed plane's
+    /// normal; negative when the plane faces away from the camera.
+    pub fn dot_with_normal(&self, dx: f32, dy: f32, dz: f32) -> f32 {
+        unsafe {
+            skia::Skia3DViewDotWithNormal(self.view, dx, dy, dz)
+        }
+    }
+}
+
+/// A camera looking at a `Patch3D`, matching the public fields of
+/// `SkCamera3D`. Units are points, with z pointing into the screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Camera3D {
+    pub location: Point3D<f32>,
+    pub axis: Vector3D<f32>,
+    pub zenith: Vector3D<f32>,
+    pub observer: Point3D<f32>,
+}
+
+impl Default for Camera3D {
+    /// Skia's default camera, eight inches in front of the origin.
+    fn default() -> Camera3D {
+        Camera3D {
+            location: Point3D::new(0.0, 0.0, -576.0),
+            axis: Vector3D::new(0.0, 0.0, 1.0),
+            zenith: Vector3D::new(0.0, -1.0, 0.0),
+            observer: Point3D::new(0.0, 0.0, -576.0),
+        }
+    }
+}
+
+impl Camera3D {
+    /// The perspective matrix that maps the unit square onto `patch` as
+    /// seen by this camera.
+    pub fn patch_to_matrix(&self, patch: &Patch3D) -> Matrix {
+        let camera = skia::SkiaCamera3D {
+            location: self.location.into(),
+            axis: self.axis.into(),
+            zenith: self.zenith.into(),
+            observer: self.observer.into(),
+        };
+        let patch = skia::SkiaPatch3D {
+            u: patch.u.into(),
+            v: patch.v.into(),
+            origin: patch.origin.into(),
+        };
+        let mut result = skia::SkiaMatrix { values: [0.0; 9] };
+        unsafe {
+            skia::SkiaSkCamera3DPatchToMatrix(&camera, &patch, &mut result);
+        }
+        Matrix::from_values(result.values)
+    }
+}
+
+/// A plane in 3D space spanned by `u` and `v` from `origin`, matching the
+/// public fields of `SkPatch3D`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Patch3D {
+    pub u: Vector3D<f32>,
+    pub v: Vector3D<f32>,
+    pub origin: Point3D<f32>,
+}
+
+impl Default for Patch3D {
+    fn default() -> Patch3D {
+        Patch3D {
+            u: Vector3D::new(1.0, 0.0, 0.0),
+            v: Vector3D::new(0.0, -1.0, 0.0),
+            origin: Point3D::origin(),
+        }
+    }
+}
@@ -0,0 +1,239 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use color::Color;
+use image::Image;
+use matrix::Matrix;
+use paint::Paint;
+use path::Path;
+use picture::Picture;
+use region::{Region, RegionOp};
+use rrect::RRect;
+use skia;
+
+use euclid::default::{Point2D, Rect, Transform2D};
+use std::marker::PhantomData;
+use std::ptr;
+
+/// Draws into a surface, borrowing the `SkCanvas` it owns.
+pub struct Canvas<'a> {
+    canvas: skia::SkiaSkCanvasRef,
+    phantom: PhantomData<&'a mut ()>,
+}
+
+impl<'a> Canvas<'a> {
+    /// Wraps a canvas owned by something else.
+    ///
+    /// # Safety
+    ///
+    /// `canvas` must stay valid for the lifetime `'a`.
+    pub unsafe fn from_raw(canvas: skia::SkiaSkCanvasRef) -> Canvas<'a> {
+        Canvas {
+            canvas,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkCanvasRef {
+        self.canvas
+    }
+
+    /// Saves the matrix and clip, returning the save count to pass to
+    /// `restore_to_count()`.
+    pub fn save(&mut self) -> i32 {
+        unsafe {
+            skia::SkiaSkCanvasSave(self.canvas)
+        }
+    }
+
+    /// Like `save`, but also redirects drawing to an offscreen layer, which
+    /// is drawn with `paint` when it is restored. Its alpha, blend mode and
+    /// image filter apply to the layer as a whole. `bounds` is a hint that
+    /// limits the size of the layer.
+    pub fn save_layer(&mut self, bounds: Option<&Rect<f32>>, paint: Option<&Paint>) -> i32 {
+        let bounds = bounds.map(|bounds| skia::SkiaRect::from(*bounds));
+        let bounds = bounds.as_ref().map_or(ptr::null(), |bounds| bounds as *const skia::SkiaRect);
+        let paint = paint.map_or(ptr::null_mut(), |paint| paint.as_raw());
+        unsafe {
+            skia::SkiaSkCanvasSaveLayer(self.canvas, bounds, paint)
+        }
+    }
+
+    pub fn restore(&mut self) {
+        unsafe {
+            skia::SkiaSkCanvasRestore(self.canvas);
+        }
+    }
+
+    pub fn restore_to_count(&mut self, save_count: i32) {
+        unsafe {
+            skia::SkiaSkCanvasRestoreToCount(self.canvas, save_count);
+        }
+    }
+
+    pub fn translate(&mut self, dx: f32, dy: f32) {
+        unsafe {
+            skia::SkiaSkCanvasTranslate(self.canvas, dx, dy);
+        }
+    }
+
+    pub fn scale(&mut self, sx: f32, sy: f32) {
+        unsafe {
+            skia::SkiaSkCanvasScale(self.canvas, sx, sy);
+        }
+    }
+
+    pub fn rotate(&mut self, degrees: f32) {
+        unsafe {
+            skia::SkiaSkCanvasRotate(self.canvas, degrees);
+        }
+    }
+
+    pub fn concat(&mut self, transform: &Transform2D<f32>) {
+        let matrix = skia::SkiaMatrix::from(*transform);
+        unsafe {
+            skia::SkiaSkCanvasConcat(self.canvas, &matrix);
+        }
+    }
+
+    pub fn set_transform(&mut self, transform: &Transform2D<f32>) {
+        let matrix = skia::SkiaMatrix::from(*transform);
+        unsafe {
+            skia::SkiaSkCanvasSetMatrix(self.canvas, &matrix);
+        }
+    }
+
+    /// Concatenates a matrix that may have perspective, such as one from
+    /// `Matrix44::to_matrix()`.
+    pub fn concat_matrix(&mut self, matrix: &Matrix) {
+        unsafe {
+            skia::SkiaSkCanvasConcat(self.canvas, matrix.as_raw());
+        }
+    }
+
+    pub fn set_matrix(&mut self, matrix: &Matrix) {
+        unsafe {
+            skia::SkiaSkCanvasSetMatrix(self.canvas, matrix.as_raw());
+        }
+    }
+
+    /// Fills the clip with `color`, replacing what was there.
+    pub fn clear(&mut self, color: Color) {
+        unsafe {
+            skia::SkiaSkCanvasClear(self.canvas, color.0);
+        }
+    }
+
+    pub fn flush(&mut self) {
+        unsafe {
+            skia::SkiaSkCanvasFlush(self.canvas);
+        }
+    }
+
+    pub fn clip_rect<R>(&mut self, rect: R, op: RegionOp, anti_alias: bool)
+                        where R: Into<skia::SkiaRect> {
+        let rect = rect.into();
+        unsafe {
+            skia::SkiaSkCanvasClipRect(self.canvas, &rect, op as i32, anti_alias);
+        }
+    }
+
+    pub fn clip_rrect(&mut self, rrect: &RRect, op: RegionOp, anti_alias: bool) {
+        unsafe {
+            skia::SkiaSkCanvasClipRRect(self.canvas, rrect.as_raw(), op as i32, anti_alias);
+        }
+    }
+
+    pub fn clip_path(&mut self, path: &Path, op: RegionOp, anti_alias: bool) {
+        unsafe {
+            skia::SkiaSkCanvasClipPath(self.canvas, path.as_raw(), op as i32, anti_alias);
+        }
+    }
+
+    /// Clips to a region in device pixels, ignoring the current matrix.
+    pub fn clip_region(&mut self, device_region: &Region, op: RegionOp) {
+        unsafe {
+            skia::SkiaSkCanvasClipRegion(self.canvas, device_region.as_raw(), op as i32);
+        }
+    }
+
+    /// Fills the whole clip with `paint`.
+    pub fn draw_paint(&mut self, paint: &Paint) {
+        unsafe {
+            skia::SkiaSkCanvasDrawPaint(self.canvas, paint.as_raw());
+        }
+    }
+
+    pub fn draw_rect<R>(&mut self, rect: R, paint: &Paint) where R: Into<skia::SkiaRect> {
+        let rect = rect.into();
+        unsafe {
+            skia::SkiaSkCanvasDrawRect(self.canvas, &rect, paint.as_raw());
+        }
+    }
+
+    pub fn draw_oval<R>(&mut self, oval: R, paint: &Paint) where R: Into<skia::SkiaRect> {
+        let oval = oval.into();
+        unsafe {
+            skia::SkiaSkCanvasDrawOval(self.canvas, &oval, paint.as_raw());
+        }
+    }
+
+    pub fn draw_rrect(&mut self, rrect: &RRect, paint: &Paint) {
+        unsafe {
+            skia::SkiaSkCanvasDrawRRect(self.canvas, rrect.as_raw(), paint.as_raw());
+        }
+    }
+
+    /// Draws the area between `outer` and `inner`, e.g. a rounded border.
+    pub fn draw_drrect(&mut self, outer: &RRect, inner: &RRect, paint: &Paint) {
+        unsafe {
+            skia::SkiaSkCanvasDrawDRRect(self.canvas, outer.as_raw(), inner.as_raw(), paint.as_raw());
+        }
+    }
+
+    pub fn draw_path(&mut self, path: &Path, paint: &Paint) {
+        unsafe {
+            skia::SkiaSkCanvasDrawPath(self.canvas, path.as_raw(), paint.as_raw());
+        }
+    }
+
+    /// Draws the region's outline, transformed by the current matrix.
+    pub fn draw_region(&mut self, region: &Region, paint: &Paint) {
+        unsafe {
+            skia::SkiaSkCanvasDrawRegion(self.canvas, region.as_raw(), paint.as_raw());
+        }
+    }
+
+    /// Draws the image with its top left corner at `point`. The paint, if
+    /// any, supplies the alpha, filtering and blend mode.
+    pub fn draw_image(&mut self, image: &Image, point: &Point2D<f32>, paint: Option<&Paint>) {
+        let paint = paint.map_or(ptr::null_mut(), |paint| paint.as_raw());
+        unsafe {
+            skia::SkiaSkCanvasDrawImage(self.canvas, image.as_raw(), point.x, point.y, paint);
+        }
+    }
+
+    /// Plays back the drawing recorded in `picture`.
+    pub fn draw_picture(&mut self, picture: &Picture) {
+        unsafe {
+            skia::SkiaSkCanvasDrawPicture(self.canvas, picture.as_raw());
+        }
+    }
+
+    /// Draws the `src` part of the image, or all of it, scaled to fill
+    /// `dst`. The paint's filter level controls the resampling quality.
+    pub fn draw_image_rect(&mut self,
+                           image: &Image,
+                           src: Option<&Rect<f32>>,
+                           dst: &Rect<f32>,
+                           paint: Option<&Paint>) {
+        let src = src.map(|src| skia::SkiaRect::from(*src));
+        let src = src.as_ref().map_or(ptr::null(), |src| src as *const skia::SkiaRect);
+        let dst = skia::SkiaRect::from(*dst);
+        let paint = paint.map_or(ptr::null_mut(), |paint| paint.as_raw());
+        unsafe {
+            skia::SkiaSkCanvasDrawImageRect(self.canvas, image.as_raw(), src, &dst, paint);
+        }
+    }
+}
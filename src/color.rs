@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// An unpremultiplied 32-bit ARGB color, laid out like `SkColor`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color(pub u32);
+
+impl Color {
+    pub const TRANSPARENT: Color = Color(0x00000000);
+    pub const BLACK: Color = Color(0xff000000);
+    pub const WHITE: Color = Color(0xffffffff);
+
+    pub fn from_argb(a: u8, r: u8, g: u8, b: u8) -> Color {
+        Color((a as u32) << 24 | (r as u32) << 16 | (g as u32) << 8 | b as u32)
+    }
+
+    pub fn a(self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    pub fn r(self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    pub fn g(self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    pub fn b(self) -> u8 {
+        self.0 as u8
+    }
+}
@@ -0,0 +1,236 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use blend_mode::BlendMode;
+use color::Color;
+use skia;
+
+use std::f32::consts::PI;
+use std::ptr;
+
+/// A component transfer function of SVG `feComponentTransfer`, which maps
+/// each value of one channel independently.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransferFunction<'a> {
+    Identity,
+    /// Interpolates linearly between the values.
+    Table(&'a [f32]),
+    /// Steps between the values.
+    Discrete(&'a [f32]),
+    Linear {
+        slope: f32,
+        intercept: f32,
+    },
+    Gamma {
+        amplitude: f32,
+        exponent: f32,
+        offset: f32,
+    },
+}
+
+impl<'a> TransferFunction<'a> {
+    /// Samples the function into the 256-entry table `SkTableColorFilter`
+    /// uses, or returns `None` for the identity.
+    pub fn to_table(&self) -> Option<[u8; 256]> {
+        let map: &dyn Fn(f32) -> f32 = match *self {
+            TransferFunction::Identity => return None,
+            TransferFunction::Table(values) | TransferFunction::Discrete(values)
+                    if values.is_empty() => return None,
+            TransferFunction::Table(values) => &move |c| {
+                let n = values.len() - 1;
+                let k = ((c * n as f32) as usize).min(n.saturating_sub(1));
+                let next = values[(k + 1).min(n)];
+                values[k] + (c * n as f32 - k as f32) * (next - values[k])
+            },
+            TransferFunction::Discrete(values) => &move |c| {
+                let n = values.len();
+                values[((c * n as f32) as usize).min(n - 1)]
+            },
+            TransferFunction::Linear { slope, intercept } => &move |c| slope * c + intercept,
+            TransferFunction::Gamma { amplitude, exponent, offset } => {
+                &move |c| amplitude * c.powf(exponent) + offset
+            }
+        };
+        let mut table = [0; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            *entry = (map(i as f32 / 255.0).clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+        Some(table)
+    }
+}
+
+/// Builds a colour matrix that maps RGB through `rgb` and adds `offset`,
+/// in 0..1 units, to each colour channel, keeping alpha.
+fn rgb_matrix(rgb: [[f32; 3]; 3], offset: f32) -> [f32; 20] {
+    let offset = offset * 255.0;
+    [rgb[0][0], rgb[0][1], rgb[0][2], 0.0, offset,
+     rgb[1][0], rgb[1][1], rgb[1][2], 0.0, offset,
+     rgb[2][0], rgb[2][1], rgb[2][2], 0.0, offset,
+     0.0, 0.0, 0.0, 1.0, 0.0]
+}
+
+fn clamp_amount(amount: f32) -> f32 {
+    amount.clamp(0.0, 1.0)
+}
+
+/// A reference counted `SkColorFilter`, which changes each colour drawn
+/// independently of its neighbours.
+pub struct ColorFilter {
+    color_filter: skia::SkiaSkColorFilterRef,
+}
+
+impl Drop for ColorFilter {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkColorFilterRelease(self.color_filter);
+        }
+    }
+}
+
+impl Clone for ColorFilter {
+    fn clone(&self) -> ColorFilter {
+        unsafe {
+            skia::SkiaSkColorFilterRetain(self.color_filter);
+        }
+        ColorFilter {
+            color_filter: self.color_filter,
+        }
+    }
+}
+
+// Color filters are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for ColorFilter {}
+unsafe impl Sync for ColorFilter {}
+
+impl ColorFilter {
+    fn from_raw(color_filter: skia::SkiaSkColorFilterRef) -> Option<ColorFilter> {
+        if color_filter.is_null() {
+            return None;
+        }
+        Some(ColorFilter { color_filter })
+    }
+
+    /// Multiplies unpremultiplied RGBA colours by a 4x5 row-major matrix, as
+    /// SVG `feColorMatrix` does. The last column is a translation in 0..255
+    /// units.
+    pub fn matrix(matrix: &[f32; 20]) -> ColorFilter {
+        ColorFilter::from_raw(unsafe { skia::SkiaSkColorFilterCreateMatrix(matrix.as_ptr()) })
+            .expect("SkColorMatrixFilter::Create failed")
+    }
+
+    /// Maps each channel through a 256-entry table, as SVG
+    /// `feComponentTransfer` does; `None` leaves a channel unchanged. The
+    /// tables apply to unpremultiplied values.
+    pub fn table(a: Option<&[u8; 256]>,
+                 r: Option<&[u8; 256]>,
+                 g: Option<&[u8; 256]>,
+                 b: Option<&[u8; 256]>)
+                 -> ColorFilter {
+        let table = |table: Option<&[u8; 256]>| table.map_or(ptr::null(), |table| table.as_ptr());
+        ColorFilter::from_raw(unsafe {
+            skia::SkiaSkColorFilterCreateTable(table(a), table(r), table(g), table(b))
+        }).expect("SkTableColorFilter::CreateARGB failed")
+    }
+
+    /// Converts the luminance of each colour to alpha and makes it black,
+    /// as SVG `feColorMatrix type="luminanceToAlpha"` and luminance masks
+    /// do.
+    pub fn luma() -> ColorFilter {
+        ColorFilter::from_raw(unsafe { skia::SkiaSkColorFilterCreateLuma() })
+            .expect("SkLumaColorFilter::Create failed")
+    }
+
+    /// Blends `color` over each colour with `mode`. Returns `None` if the
+    /// combination leaves every colour unchanged, such as a transparent
+    /// colour with source-over.
+    pub fn mode(color: Color, mode: BlendMode) -> Option<ColorFilter> {
+        ColorFilter::from_raw(unsafe { skia::SkiaSkColorFilterCreateMode(color.0, mode as i32) })
+    }
+
+    /// Multiplies the RGB channels by those of `mul` and adds those of `add`,
+    /// keeping alpha.
+    pub fn lighting(mul: Color, add: Color) -> ColorFilter {
+        ColorFilter::from_raw(unsafe { skia::SkiaSkColorFilterCreateLighting(mul.0, add.0) })
+            .expect("SkColorFilter::CreateLightingFilter failed")
+    }
+
+    /// Returns one filter that applies `inner` and then `outer`.
+    ///
+    /// Only matrix filters can be folded together, and only if `inner` never
+    /// needs clamping, as applying them in turn clamps in between. Otherwise
+    /// this returns `None`, and the filters can be chained as
+    /// `ImageFilter::color_filter` nodes instead.
+    pub fn compose(outer: &ColorFilter, inner: &ColorFilter) -> Option<ColorFilter> {
+        ColorFilter::from_raw(unsafe {
+            skia::SkiaSkColorFilterCompose(outer.as_raw(), inner.as_raw())
+        })
+    }
+
+    /// CSS `grayscale()`, with `amount` from 0 to 1.
+    pub fn grayscale(amount: f32) -> ColorFilter {
+        let s = 1.0 - clamp_amount(amount);
+        ColorFilter::matrix(&rgb_matrix([[0.2126 + 0.7874 * s, 0.7152 - 0.7152 * s, 0.0722 - 0.0722 * s],
+                                         [0.2126 - 0.2126 * s, 0.7152 + 0.2848 * s, 0.0722 - 0.0722 * s],
+                                         [0.2126 - 0.2126 * s, 0.7152 - 0.7152 * s, 0.0722 + 0.9278 * s]],
+                                        0.0))
+    }
+
+    /// CSS `sepia()`, with `amount` from 0 to 1.
+    pub fn sepia(amount: f32) -> ColorFilter {
+        let s = 1.0 - clamp_amount(amount);
+        ColorFilter::matrix(&rgb_matrix([[0.393 + 0.607 * s, 0.769 - 0.769 * s, 0.189 - 0.189 * s],
+                                         [0.349 - 0.349 * s, 0.686 + 0.314 * s, 0.168 - 0.168 * s],
+                                         [0.272 - 0.272 * s, 0.534 - 0.534 * s, 0.131 + 0.869 * s]],
+                                        0.0))
+    }
+
+    /// CSS `saturate()`, where 0 is fully desaturated and 1 is unchanged.
+    pub fn saturate(amount: f32) -> ColorFilter {
+        let s = amount.max(0.0);
+        ColorFilter::matrix(&rgb_matrix([[0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+                                         [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+                                         [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s]],
+                                        0.0))
+    }
+
+    /// CSS `hue-rotate()`, also SVG `feColorMatrix type="hueRotate"`.
+    pub fn hue_rotate(degrees: f32) -> ColorFilter {
+        let (sin, cos) = (degrees * PI / 180.0).sin_cos();
+        ColorFilter::matrix(&rgb_matrix([[0.213 + cos * 0.787 - sin * 0.213,
+                                          0.715 - cos * 0.715 - sin * 0.715,
+                                          0.072 - cos * 0.072 + sin * 0.928],
+                                         [0.213 - cos * 0.213 + sin * 0.143,
+                                          0.715 + cos * 0.285 + sin * 0.140,
+                                          0.072 - cos * 0.072 - sin * 0.283],
+                                         [0.213 - cos * 0.213 - sin * 0.787,
+                                          0.715 - cos * 0.715 + sin * 0.715,
+                                          0.072 + cos * 0.928 + sin * 0.072]],
+                                        0.0))
+    }
+
+    /// CSS `brightness()`, where 1 is unchanged.
+    pub fn brightness(amount: f32) -> ColorFilter {
+        let a = amount.max(0.0);
+        ColorFilter::matrix(&rgb_matrix([[a, 0.0, 0.0], [0.0, a, 0.0], [0.0, 0.0, a]], 0.0))
+    }
+
+    /// CSS `contrast()`, where 1 is unchanged.
+    pub fn contrast(amount: f32) -> ColorFilter {
+        let a = amount.max(0.0);
+        ColorFilter::matrix(&rgb_matrix([[a, 0.0, 0.0], [0.0, a, 0.0], [0.0, 0.0, a]],
+                                        0.5 - 0.5 * a))
+    }
+
+    /// CSS `invert()`, with `amount` from 0 to 1.
+    pub fn invert(amount: f32) -> ColorFilter {
+        let a = clamp_amount(amount);
+        let scale = 1.0 - 2.0 * a;
+        ColorFilter::matrix(&rgb_matrix([[scale, 0.0, 0.0], [0.0, scale, 0.0], [0.0, 0.0, scale]],
+                                        a))
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkColorFilterRef {
+        self.color_filter
+    }
+}
@@ -0,0 +1,134 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Block-compressed textures.
+//!
+//! Skia can upload ETC1 and LATC data straight to the GPU when the driver
+//! supports it, and compress raster pixels into either format. Both are made
+//! of 4x4 pixel blocks of 8 bytes each. `SkTextureCompressor` in this tree
+//! does not implement R11 EAC, so it is not offered here.
+
+use gl_context::GLContext;
+use image::Image;
+use image_info::ColorType;
+use pixmap::Pixmap;
+use skia;
+
+use euclid::default::Size2D;
+use libc::{c_void, size_t};
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressedFormat {
+    /// RGB colour without alpha.
+    Etc1 = 0,
+    /// A single channel, used for alpha masks.
+    Latc = 1,
+}
+
+impl CompressedFormat {
+    /// Returns the number of bytes needed to hold an image of `size`, or
+    /// `None` if either dimension is not a positive multiple of 4.
+    pub fn data_size(self, size: Size2D<i32>) -> Option<usize> {
+        if size.width <= 0 || size.height <= 0 || size.width % 4 != 0 || size.height % 4 != 0 {
+            return None;
+        }
+        Some((size.width as usize / 4) * (size.height as usize / 4) * 8)
+    }
+}
+
+/// Compresses `src` to ETC1, dropping any alpha.
+///
+/// The source must be RGB565 or in the native 32-bit format, and its
+/// dimensions must be multiples of 4.
+pub fn compress_etc1(src: &Pixmap) -> Option<Vec<u8>> {
+    match src.info().color_type {
+        ColorType::Rgb565 | ColorType::N32 => {}
+        _ => return None,
+    }
+    compress(src, CompressedFormat::Etc1, skia::SkiaETC1EncodeImage)
+}
+
+/// Compresses `src` to LATC.
+///
+/// The source must be alpha-only, and its dimensions must be multiples of 4.
+pub fn compress_latc(src: &Pixmap) -> Option<Vec<u8>> {
+    if src.info().color_type != ColorType::Alpha8 {
+        return None;
+    }
+    compress(src, CompressedFormat::Latc, skia::SkiaSkTextureCompressorCompressLATC)
+}
+
+type CompressFn = unsafe extern "C" fn(*const skia::SkiaImageInfo,
+                                       *const c_void,
+                                       size_t,
+                                       *mut c_void,
+                                       size_t) -> bool;
+
+fn compress(src: &Pixmap, format: CompressedFormat, compress: CompressFn) -> Option<Vec<u8>> {
+    let info = src.info();
+    let mut result = vec![0; format.data_size(info.size())?];
+    let raw_info = skia::SkiaImageInfo::from(*info);
+    if unsafe {
+        compress(&raw_info,
+                 src.pixels().as_ptr() as *const c_void,
+                 src.row_bytes() as size_t,
+                 result.as_mut_ptr() as *mut c_void,
+                 result.len() as size_t)
+    } {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+impl Image {
+    /// Uploads compressed `data` of the given size as a texture in
+    /// `gl_context` and wraps it in an image.
+    ///
+    /// Returns `None` if the size is not made of whole blocks, `data` is too
+    /// short, or the GPU cannot sample the format.
+    pub fn from_compressed_texture(gl_context: &GLContext,
+                                   format: CompressedFormat,
+                                   size: Size2D<i32>,
+                                   data: &[u8])
+                                   -> Option<Image> {
+        if data.len() < format.data_size(size)? {
+            return None;
+        }
+        gl_context.make_current();
+        unsafe {
+            let image = skia::SkiaSkImageCreateFromCompressedTexture(gl_context.gr_context,
+                                                                    format as i32,
+                                                                    size.width,
+                                                                    size.height,
+                                                                    data.as_ptr() as *const c_void,
+                                                                    data.len() as size_t);
+            if image.is_null() {
+                None
+            } else {
+                Some(Image::from_raw(image))
+            }
+        }
+    }
+
+    /// Uploads the ETC1 data in a PKM or KTX file as a texture in
+    /// `gl_context`.
+    pub fn from_compressed_container(gl_context: &GLContext, data: &[u8]) -> Option<Image> {
+        let (mut width, mut height, mut offset) = (0, 0, 0);
+        if !unsafe {
+            skia::SkiaETC1ContainerGetData(data.as_ptr() as *const c_void,
+                                           data.len() as size_t,
+                                           &mut width,
+                                           &mut height,
+                                           &mut offset)
+        } {
+            return None;
+        }
+        Image::from_compressed_texture(gl_context,
+                                       CompressedFormat::Etc1,
+                                       Size2D::new(width, height),
+                                       &data[offset..])
+    }
+}
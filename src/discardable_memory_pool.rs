@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use skia;
+
+use libc::size_t;
+
+/// Skia's global `SkDiscardableMemoryPool`, which holds the pixels of lazily
+/// decoded images.
+///
+/// Whenever the pool grows past its budget, it frees the least recently used
+/// memory that is not locked for drawing; that memory is decoded again the
+/// next time it is needed. The default budget is 128MB.
+#[derive(Clone, Copy, Debug)]
+pub struct DiscardableMemoryPool {
+    _private: (),
+}
+
+impl DiscardableMemoryPool {
+    pub fn global() -> DiscardableMemoryPool {
+        DiscardableMemoryPool { _private: () }
+    }
+
+    /// The number of bytes currently allocated from the pool.
+    pub fn ram_used(&self) -> usize {
+        unsafe {
+            skia::SkiaSkDiscardableMemoryPoolGetRAMUsed() as usize
+        }
+    }
+
+    pub fn ram_budget(&self) -> usize {
+        unsafe {
+            skia::SkiaSkDiscardableMemoryPoolGetRAMBudget() as usize
+        }
+    }
+
+    /// Sets the budget in bytes, purging unlocked memory straight away if the
+    /// pool is over it.
+    pub fn set_ram_budget(&self, budget: usize) {
+        unsafe {
+            skia::SkiaSkDiscardableMemoryPoolSetRAMBudget(budget as size_t);
+        }
+    }
+
+    /// Frees all memory that is not locked for drawing.
+    pub fn purge(&self) {
+        unsafe {
+            skia::SkiaSkDiscardableMemoryPoolPurge();
+        }
+    }
+}
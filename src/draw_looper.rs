@@ -0,0 +1,216 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use blend_mode::BlendMode;
+use color::Color;
+use mask_filter::{BlurStyle, MaskFilter};
+use paint::Paint;
+use skia;
+
+use euclid::default::Vector2D;
+
+/// One pass of a `DrawLooper` built with `DrawLooperBuilder`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layer {
+    /// Which parts of the layer's paint replace those of the draw's paint,
+    /// from the `Layer` constants. The colour is always combined through
+    /// `color_mode` instead.
+    pub paint_bits: u32,
+    /// Combines the layer paint's colour, as the source, with the draw
+    /// paint's, as the destination. `Src` takes the layer's colour and `Dst`
+    /// keeps the draw's.
+    pub color_mode: BlendMode,
+    pub offset: Vector2D<f32>,
+    /// Applies `offset` in device space, so the canvas transform does not
+    /// scale or rotate it.
+    pub post_translate: bool,
+    /// Blurs the pass with a Gaussian of this standard deviation, replacing
+    /// the layer paint's mask filter. Zero leaves it sharp.
+    pub blur_sigma: f32,
+}
+
+impl Default for Layer {
+    /// Draws with the draw's paint unchanged.
+    fn default() -> Layer {
+        Layer {
+            paint_bits: 0,
+            color_mode: BlendMode::Dst,
+            offset: Vector2D::zero(),
+            post_translate: false,
+            blur_sigma: 0.0,
+        }
+    }
+}
+
+impl Layer {
+    pub const STYLE: u32 = 1 << 0;
+    pub const TEXT_SKEW_X: u32 = 1 << 1;
+    pub const PATH_EFFECT: u32 = 1 << 2;
+    pub const MASK_FILTER: u32 = 1 << 3;
+    pub const SHADER: u32 = 1 << 4;
+    pub const COLOR_FILTER: u32 = 1 << 5;
+    pub const XFERMODE: u32 = 1 << 6;
+    /// Everything but the text encoding, which the draw call relies on.
+    pub const ENTIRE_PAINT: u32 = !0;
+
+    fn to_raw(self, paint: &Paint) -> Option<(skia::SkiaLayerInfo, Paint)> {
+        let mut paint = paint.clone();
+        let mut paint_bits = self.paint_bits;
+        if self.blur_sigma != 0.0 {
+            let mask_filter = MaskFilter::blur(BlurStyle::Normal, self.blur_sigma, 0)?;
+            paint.set_mask_filter(Some(&mask_filter));
+            paint_bits |= Layer::MASK_FILTER;
+        }
+        let info = skia::SkiaLayerInfo {
+            paint_bits,
+            color_mode: self.color_mode as i32,
+            offset: skia::SkiaPoint {
+                x: self.offset.x,
+                y: self.offset.y,
+            },
+            post_translate: self.post_translate,
+        };
+        Some((info, paint))
+    }
+}
+
+/// A reference counted `SkDrawLooper`, which turns each draw made with a
+/// paint into several passes.
+pub struct DrawLooper {
+    looper: skia::SkiaSkDrawLooperRef,
+}
+
+impl Drop for DrawLooper {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkDrawLooperRelease(self.looper);
+        }
+    }
+}
+
+impl Clone for DrawLooper {
+    fn clone(&self) -> DrawLooper {
+        unsafe {
+            skia::SkiaSkDrawLooperRetain(self.looper);
+        }
+        DrawLooper {
+            looper: self.looper,
+        }
+    }
+}
+
+// Draw loopers are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for DrawLooper {}
+unsafe impl Sync for DrawLooper {}
+
+impl DrawLooper {
+    /// A flag for `blur_shadow`: the offset and blur are not scaled by the
+    /// canvas transform.
+    pub const BLUR_IGNORE_TRANSFORM: u32 = 0x01;
+    /// A flag for `blur_shadow`: the shadow ignores the paint's colour and
+    /// shader and is drawn in `color`.
+    pub const BLUR_OVERRIDE_COLOR: u32 = 0x02;
+    /// A flag for `blur_shadow`: uses a slower, closer approximation of a
+    /// Gaussian.
+    pub const BLUR_HIGH_QUALITY: u32 = 0x04;
+
+    fn from_raw(looper: skia::SkiaSkDrawLooperRef) -> Option<DrawLooper> {
+        if looper.is_null() {
+            return None;
+        }
+        Some(DrawLooper { looper })
+    }
+
+    /// `SkBlurDrawLooper`: draws a single blurred shadow at `offset`, then
+    /// the draw itself. `flags` combine the `BLUR_*` constants.
+    pub fn blur_shadow(color: Color, sigma: f32, offset: Vector2D<f32>, flags: u32)
+                       -> Option<DrawLooper> {
+        DrawLooper::from_raw(unsafe {
+            skia::SkiaSkBlurDrawLooperCreate(color.0, sigma, offset.x, offset.y, flags)
+        })
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkDrawLooperRef {
+        self.looper
+    }
+}
+
+/// Stacks `Layer`s into an `SkLayerDrawLooper`.
+///
+/// For CSS `text-shadow`, add a default layer for the text itself, then
+/// each shadow in the order given, since later layers draw underneath.
+pub struct DrawLooperBuilder {
+    builder: skia::SkiaSkLayerDrawLooperBuilderRef,
+}
+
+impl Drop for DrawLooperBuilder {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkLayerDrawLooperBuilderDelete(self.builder);
+        }
+    }
+}
+
+// The builder is uniquely owned and only touched through &mut self.
+unsafe impl Send for DrawLooperBuilder {}
+
+impl Default for DrawLooperBuilder {
+    fn default() -> DrawLooperBuilder {
+        DrawLooperBuilder::new()
+    }
+}
+
+impl DrawLooperBuilder {
+    pub fn new() -> DrawLooperBuilder {
+        DrawLooperBuilder {
+            builder: unsafe { skia::SkiaSkLayerDrawLooperBuilderCreate() },
+        }
+    }
+
+    /// Adds a pass below all those added so far, drawn with the parts of
+    /// `paint` picked by `layer`. Returns `false` if the offset or blur is
+    /// not finite, or the blur is negative.
+    pub fn add_layer(&mut self, layer: &Layer, paint: &Paint) -> bool {
+        let (info, paint) = match layer.to_raw(paint) {
+            Some(raw) => raw,
+            None => return false,
+        };
+        unsafe { skia::SkiaSkLayerDrawLooperBuilderAddLayer(self.builder, &info, paint.as_raw()) }
+    }
+
+    /// Like `add_layer`, but adds the pass above all those added so far.
+    pub fn add_layer_on_top(&mut self, layer: &Layer, paint: &Paint) -> bool {
+        let (info, paint) = match layer.to_raw(paint) {
+            Some(raw) => raw,
+            None => return false,
+        };
+        unsafe {
+            skia::SkiaSkLayerDrawLooperBuilderAddLayerOnTop(self.builder, &info, paint.as_raw())
+        }
+    }
+
+    /// Adds a shadow in `color` below all the passes added so far, as one
+    /// CSS `text-shadow` or `box-shadow` entry.
+    pub fn add_shadow(&mut self, offset: Vector2D<f32>, blur_sigma: f32, color: Color) -> bool {
+        let mut paint = Paint::new();
+        paint.set_color(color);
+        // Drop any shader so the shadow is a flat colour.
+        let layer = Layer {
+            paint_bits: Layer::SHADER,
+            color_mode: BlendMode::Src,
+            offset,
+            blur_sigma,
+            ..Layer::default()
+        };
+        self.add_layer(&layer, &paint)
+    }
+
+    /// Returns a looper with the layers added so far and empties the
+    /// builder for reuse.
+    pub fn build(&mut self) -> DrawLooper {
+        DrawLooper::from_raw(unsafe {
+            skia::SkiaSkLayerDrawLooperBuilderDetachLooper(self.builder)
+        }).expect("SkLayerDrawLooper::Builder::detachLooper failed")
+    }
+}
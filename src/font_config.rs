@@ -0,0 +1,204 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use skia;
+use typeface::FontStyle;
+
+use libc::{c_char, c_void, size_t};
+use std::borrow::Cow;
+use std::char;
+use std::ffi::CStr;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+/// A font as a `FontConfigInterface` identifies it, which Skia hands back to
+/// `open_stream` when it needs the font's data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontIdentity {
+    /// Free for the implementation's own use, such as a key into its tables.
+    pub id: u32,
+    pub ttc_index: i32,
+    /// Whatever the implementation finds the font by, such as its path.
+    pub name: String,
+    pub style: FontStyle,
+}
+
+impl FontIdentity {
+    fn from_raw(identity: &skia::SkiaFontIdentity) -> FontIdentity {
+        let name = if identity.string.is_null() {
+            String::new()
+        } else {
+            let bytes = unsafe {
+                slice::from_raw_parts(identity.string as *const u8, identity.string_length)
+            };
+            String::from_utf8_lossy(bytes).into_owned()
+        };
+        FontIdentity {
+            id: identity.id,
+            ttc_index: identity.ttc_index,
+            name,
+            style: FontStyle::from_raw(identity.style),
+        }
+    }
+}
+
+/// A font found by a `FontConfigInterface`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontMatch {
+    pub identity: FontIdentity,
+    /// The family the font belongs to, which may differ from the one asked
+    /// for when that is an alias or not installed.
+    pub family_name: String,
+}
+
+/// Font lookup for Skia's fontconfig port, bridged to an
+/// `SkFontConfigInterface`.
+///
+/// Skia normally asks libfontconfig and opens font files itself. A process
+/// that cannot, such as a sandboxed content process, can install one of
+/// these with `set_font_config_interface` to have another process answer
+/// for it, for instance over IPC. The methods are called from whichever
+/// thread is loading or drawing text. A panic in one is caught and treated
+/// as finding nothing.
+///
+/// Not every lookup goes through the interface:
+///
+/// - `open_stream` reads the data of every typeface Skia finds, whichever
+///   way it was found. `Typeface::from_file` and `from_data` do not use
+///   the interface at all.
+/// - `Typeface::from_family`, `FontMgr::match_family_style` and
+///   `match_face_style` first ask libfontconfig for the family's faces,
+///   and call `match_family_name` only when that finds none.
+/// - `FontMgr::match_family_style_character` calls `match_character`
+///   first, and asks libfontconfig only when that finds nothing.
+/// - `FontMgr::match_family` and `style_set` only ask libfontconfig.
+/// - `FontMgr::family_count`, `family_name` and `family_names` list no
+///   families, since this trait has no way to enumerate them.
+///
+/// In a sandbox, libfontconfig usually cannot reach any fonts, so the
+/// direct lookups find nothing rather than failing.
+pub trait FontConfigInterface: Send + Sync {
+    /// The face of `family_name` closest to `style`, or of the default font
+    /// if the family is `None` or not installed. Skia's legacy callers only
+    /// ask for normal or bold weights, upright or italic.
+    fn match_family_name(&self, family_name: Option<&str>, style: &FontStyle)
+                         -> Option<FontMatch>;
+
+    /// The whole contents of the font file that `identity` came from. Skia
+    /// keeps a copy for as long as any typeface uses it.
+    fn open_stream(&self, identity: &FontIdentity) -> Option<Vec<u8>>;
+
+    /// A fallback face with a glyph for `character`, preferring
+    /// `family_name`, faces close to `style` and fonts for the BCP 47
+    /// language tag `language`. The default finds none, which leaves Skia
+    /// to ask libfontconfig directly.
+    fn match_character(&self,
+                       _family_name: Option<&str>,
+                       _style: &FontStyle,
+                       _language: Option<&str>,
+                       _character: char)
+                       -> Option<FontMatch> {
+        None
+    }
+}
+
+/// Installs `interface` as the process's `SkFontConfigInterface`, replacing
+/// the one that calls libfontconfig.
+///
+/// The default `FontMgr` keeps the interface it was created with, so this
+/// should be called before any font is loaded.
+pub fn set_font_config_interface<F: FontConfigInterface + 'static>(interface: F) {
+    let callbacks = skia::SkiaFontConfigCallbacks {
+        match_family_name: match_family_name::<F>,
+        open_stream: open_stream::<F>,
+        match_character: match_character::<F>,
+        destroy: destroy::<F>,
+    };
+    // Skia owns the interface from here on and calls `destroy` once it is
+    // replaced and no font still uses it.
+    let closure = Box::into_raw(Box::new(interface)) as *mut c_void;
+    unsafe {
+        skia::SkiaSkFontConfigInterfaceSetGlobal(&callbacks, closure);
+    }
+}
+
+fn optional_str<'a>(string: *const c_char) -> Option<Cow<'a, str>> {
+    if string.is_null() {
+        return None;
+    }
+    Some(unsafe { CStr::from_ptr(string) }.to_string_lossy())
+}
+
+fn set_match(result: skia::SkiaFontMatchRef, font_match: &FontMatch) {
+    let identity = skia::SkiaFontIdentity {
+        id: font_match.identity.id,
+        ttc_index: font_match.identity.ttc_index,
+        string: font_match.identity.name.as_ptr() as *const c_char,
+        string_length: font_match.identity.name.len() as size_t,
+        style: font_match.identity.style.to_raw(),
+    };
+    unsafe {
+        skia::SkiaFontMatchSet(result,
+                               &identity,
+                               font_match.family_name.as_ptr() as *const c_char,
+                               font_match.family_name.len() as size_t);
+    }
+}
+
+extern "C" fn match_family_name<F: FontConfigInterface>(closure: *mut c_void,
+                                                        family_name: *const c_char,
+                                                        style: *const skia::SkiaFontStyle,
+                                                        result: skia::SkiaFontMatchRef) {
+    let interface = unsafe { &*(closure as *const F) };
+    let family_name = optional_str(family_name);
+    let style = FontStyle::from_raw(unsafe { *style });
+    // Unwinding into Skia is undefined, so a panic is treated as no match.
+    let font_match = panic::catch_unwind(AssertUnwindSafe(|| {
+        interface.match_family_name(family_name.as_deref(), &style)
+    }));
+    if let Ok(Some(font_match)) = font_match {
+        set_match(result, &font_match);
+    }
+}
+
+extern "C" fn open_stream<F: FontConfigInterface>(closure: *mut c_void,
+                                                  identity: *const skia::SkiaFontIdentity)
+                                                  -> skia::SkiaSkDataRef {
+    let interface = unsafe { &*(closure as *const F) };
+    let identity = FontIdentity::from_raw(unsafe { &*identity });
+    match panic::catch_unwind(AssertUnwindSafe(|| interface.open_stream(&identity))) {
+        Ok(Some(ref data)) if !data.is_empty() => unsafe {
+            skia::SkiaSkDataCreateWithCopy(data.as_ptr() as *const c_void, data.len() as size_t)
+        },
+        _ => ptr::null_mut(),
+    }
+}
+
+extern "C" fn match_character<F: FontConfigInterface>(closure: *mut c_void,
+                                                      family_name: *const c_char,
+                                                      style: *const skia::SkiaFontStyle,
+                                                      bcp47: *const c_char,
+                                                      character: u32,
+                                                      result: skia::SkiaFontMatchRef) {
+    let interface = unsafe { &*(closure as *const F) };
+    let character = match char::from_u32(character) {
+        Some(character) => character,
+        None => return,
+    };
+    let family_name = optional_str(family_name);
+    let language = optional_str(bcp47).filter(|language| !language.is_empty());
+    let style = FontStyle::from_raw(unsafe { *style });
+    let font_match = panic::catch_unwind(AssertUnwindSafe(|| {
+        interface.match_character(family_name.as_deref(), &style, language.as_deref(), character)
+    }));
+    if let Ok(Some(font_match)) = font_match {
+        set_match(result, &font_match);
+    }
+}
+
+extern "C" fn destroy<F: FontConfigInterface>(closure: *mut c_void) {
+    let interface = unsafe { Box::from_raw(closure as *mut F) };
+    let _ = panic::catch_unwind(AssertUnwindSafe(move || drop(interface)));
+}
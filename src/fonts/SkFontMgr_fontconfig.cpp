@@ -10,9 +10,11 @@
 #include "SkFontConfigInterface.h"
 #include "SkFontConfigTypeface.h"
 #include "SkMath.h"
+#include "SkStream.h"
 #include "SkString.h"
 #include "SkTDArray.h"
 #include "SkThread.h"
+#include "SkTypefaceCache.h"
 
 // for now we pull these in directly. eventually we will solely rely on the
 // SkFontConfigInterface instance.
@@ -54,6 +56,8 @@ private:
 // Defined in SkFontHost_FreeType.cpp
 bool find_name_and_attributes(SkStream* stream, SkString* name,
                               SkTypeface::Style* style, bool* isFixedWidth);
+bool find_name_and_attributes(SkStream* stream, int ttcIndex, SkString* name,
+                              SkTypeface::Style* style, bool* isFixedWidth);
 
 // borrow this global from SkFontHost_fontconfig. eventually that file should
 // go away, and be replaced with this one.
@@ -154,41 +158,145 @@ public:
 
 private:
     struct Rec {
+        SkString    fFamilyName;
         SkString    fStyleName;
         SkString    fFileName;
+        int         fTTCIndex;
         SkFontStyle fStyle;
     };
     Rec* fRecs;
     int  fRecCount;
 };
 
-static int map_range(int value,
-                     int old_min, int old_max, int new_min, int new_max) {
-    SkASSERT(old_min < old_max);
-    SkASSERT(new_min < new_max);
-    return new_min + SkMulDiv(value - old_min,
-                              new_max - new_min, old_max - old_min);
+struct MapRanges {
+    int fc_val;
+    int sk_val;
+};
+
+// Maps value through the piecewise linear function given by ranges, from the
+// fontconfig scale to the SkFontStyle one or back, clamping at either end.
+// The ranges must be sorted on both scales.
+static int map_ranges(int value, const MapRanges ranges[], int count, bool toFontconfig) {
+    const int MapRanges::* from = toFontconfig ? &MapRanges::sk_val : &MapRanges::fc_val;
+    const int MapRanges::* to = toFontconfig ? &MapRanges::fc_val : &MapRanges::sk_val;
+    if (value <= ranges[0].*from) {
+        return ranges[0].*to;
+    }
+    for (int i = 1; i < count; ++i) {
+        if (value <= ranges[i].*from) {
+            const MapRanges& lo = ranges[i - 1];
+            const MapRanges& hi = ranges[i];
+            return lo.*to + SkMulDiv(value - lo.*from, hi.*to - lo.*to, hi.*from - lo.*from);
+        }
+    }
+    return ranges[count - 1].*to;
 }
 
+// fontconfig has its own scales for weight and width, so map their named
+// values onto the SkFontStyle ones.
+static const MapRanges gWeightRanges[] = {
+    { FC_WEIGHT_THIN,       SkFontStyle::kThin_Weight },
+    { FC_WEIGHT_EXTRALIGHT, SkFontStyle::kExtraLight_Weight },
+    { FC_WEIGHT_LIGHT,      SkFontStyle::kLight_Weight },
+    { FC_WEIGHT_REGULAR,    SkFontStyle::kNormal_Weight },
+    { FC_WEIGHT_MEDIUM,     SkFontStyle::kMedium_Weight },
+    { FC_WEIGHT_DEMIBOLD,   SkFontStyle::kSemiBold_Weight },
+    { FC_WEIGHT_BOLD,       SkFontStyle::kBold_Weight },
+    { FC_WEIGHT_EXTRABOLD,  SkFontStyle::kExtraBold_Weight },
+    { FC_WEIGHT_BLACK,      SkFontStyle::kBlack_Weight },
+};
+static const MapRanges gWidthRanges[] = {
+    { FC_WIDTH_ULTRACONDENSED, SkFontStyle::kUltraCondensed_Width },
+    { FC_WIDTH_EXTRACONDENSED, SkFontStyle::kExtraCondensed_Width },
+    { FC_WIDTH_CONDENSED,      SkFontStyle::kCondensed_Width },
+    { FC_WIDTH_SEMICONDENSED,  SkFontStyle::kSemiCondensed_Width },
+    { FC_WIDTH_NORMAL,         SkFontStyle::kNormal_Width },
+    { FC_WIDTH_SEMIEXPANDED,   SkFontStyle::kSemiExpanded_Width },
+    { FC_WIDTH_EXPANDED,       SkFontStyle::kExpanded_Width },
+    { FC_WIDTH_EXTRAEXPANDED,  SkFontStyle::kExtraExpanded_Width },
+    { FC_WIDTH_ULTRAEXPANDED,  SkFontStyle::kUltaExpanded_Width },
+};
+
 static SkFontStyle make_fontconfig_style(FcPattern* match) {
     int weight = get_int(match, FC_WEIGHT);
     int width = get_int(match, FC_WIDTH);
     int slant = get_int(match, FC_SLANT);
-//    SkDebugf("old weight %d new weight %d\n", weight, map_range(weight, 0, 80, 0, 400));
 
-    // fontconfig weight seems to be 0..200 or so, so we remap it here
-    weight = map_range(weight, 0, 80, 0, 400);
-    width = map_range(width, 0, 200, 0, 9);
+    weight = SK_MinS32 == weight ? (int)SkFontStyle::kNormal_Weight
+                                 : map_ranges(weight, gWeightRanges,
+                                              SK_ARRAY_COUNT(gWeightRanges), false);
+    width = SK_MinS32 == width ? (int)SkFontStyle::kNormal_Width
+                               : map_ranges(width, gWidthRanges,
+                                            SK_ARRAY_COUNT(gWidthRanges), false);
     return SkFontStyle(weight, width, slant > 0 ? SkFontStyle::kItalic_Slant
                                                 : SkFontStyle::kUpright_Slant);
 }
 
+static void add_fontconfig_style(FcPattern* pattern, const SkFontStyle& style) {
+    SkASSERT(gFCSafeToUse);
+    FcPatternAddInteger(pattern, FC_WEIGHT,
+                        map_ranges(style.weight(), gWeightRanges,
+                                   SK_ARRAY_COUNT(gWeightRanges), true));
+    FcPatternAddInteger(pattern, FC_WIDTH,
+                        map_ranges(style.width(), gWidthRanges,
+                                   SK_ARRAY_COUNT(gWidthRanges), true));
+    FcPatternAddInteger(pattern, FC_SLANT,
+                        style.isItalic() ? FC_SLANT_ITALIC : FC_SLANT_ROMAN);
+}
+
+static SkTypeface::Style to_legacy_style(const SkFontStyle& style) {
+    int bits = SkTypeface::kNormal;
+    if (style.weight() >= SkFontStyle::kSemiBold_Weight) {
+        bits |= SkTypeface::kBold;
+    }
+    if (style.isItalic()) {
+        bits |= SkTypeface::kItalic;
+    }
+    return (SkTypeface::Style)bits;
+}
+
+static bool find_by_identity(SkTypeface* face, SkTypeface::Style, void* ctx) {
+    const FontConfigTypeface* fct = (FontConfigTypeface*)face;
+    const SkFontConfigInterface::FontIdentity* identity =
+            (const SkFontConfigInterface::FontIdentity*)ctx;
+    return NULL == fct->getLocalStream() && fct->getIdentity() == *identity;
+}
+
+// Returns the typeface for a face found by fontconfig or the
+// SkFontConfigInterface, sharing it with any earlier request for the same face.
+static SkTypeface* create_typeface(const SkFontConfigInterface::FontIdentity& identity,
+                                   const SkString& familyName) {
+    SkTypeface* face = SkTypefaceCache::FindByProcAndRef(find_by_identity,
+                                                         (void*)&identity);
+    if (face) {
+        return face;
+    }
+
+    SkTypeface::Style legacyStyle = to_legacy_style(identity.fStyle);
+    face = FontConfigTypeface::Create(legacyStyle, identity, familyName);
+    SkTypefaceCache::Add(face, legacyStyle);
+    return face;
+}
+
+static SkTypeface* create_typeface(const SkString& fileName, int ttcIndex,
+                                   const SkString& familyName,
+                                   const SkFontStyle& style) {
+    SkFontConfigInterface::FontIdentity identity;
+    identity.fString = fileName;
+    identity.fTTCIndex = ttcIndex;
+    identity.fStyle = style;
+    return create_typeface(identity, familyName);
+}
+
 SkFontStyleSet_FC::SkFontStyleSet_FC(FcPattern** matches, int count) {
     fRecCount = count;
     fRecs = SkNEW_ARRAY(Rec, count);
     for (int i = 0; i < count; ++i) {
+        fRecs[i].fFamilyName.set(get_name(matches[i], FC_FAMILY));
         fRecs[i].fStyleName.set(get_name(matches[i], FC_STYLE));
         fRecs[i].fFileName.set(get_name(matches[i], FC_FILE));
+        int ttcIndex = get_int(matches[i], FC_INDEX);
+        fRecs[i].fTTCIndex = SK_MinS32 == ttcIndex ? 0 : ttcIndex;
         fRecs[i].fStyle = make_fontconfig_style(matches[i]);
     }
 }
@@ -209,11 +317,29 @@ void SkFontStyleSet_FC::getStyle(int index, SkFontStyle* style,
 }
 
 SkTypeface* SkFontStyleSet_FC::createTypeface(int index) {
-    return NULL;
+    if ((unsigned)index >= (unsigned)fRecCount) {
+        return NULL;
+    }
+    const Rec& rec = fRecs[index];
+    return create_typeface(rec.fFileName, rec.fTTCIndex, rec.fFamilyName, rec.fStyle);
 }
 
 SkTypeface* SkFontStyleSet_FC::matchStyle(const SkFontStyle& pattern) {
-    return NULL;
+    // Roughly the CSS font matching order: width matters most, then slant,
+    // then weight.
+    int bestIndex = -1;
+    int bestScore = SK_MaxS32;
+    for (int i = 0; i < fRecCount; ++i) {
+        const SkFontStyle& style = fRecs[i].fStyle;
+        int score = SkAbs32(style.width() - pattern.width()) * 10000 +
+                    (style.slant() != pattern.slant() ? 1000 : 0) +
+                    SkAbs32(style.weight() - pattern.weight());
+        if (score < bestScore) {
+            bestIndex = i;
+            bestScore = score;
+        }
+    }
+    return bestIndex < 0 ? NULL : this->createTypeface(bestIndex);
 }
 
 class SkFontMgr_fontconfig : public SkFontMgr {
@@ -285,15 +411,98 @@ protected:
         SkFontStyleSet_FC* sset = SkNEW_ARGS(SkFontStyleSet_FC,
                                              (trimmedMatches.begin(),
                                               trimmedMatches.count()));
+        // The style set copies everything it needs out of the patterns.
+        FcFontSetDestroy(font_set);
         return sset;
     }
 
     virtual SkTypeface* onMatchFamilyStyle(const char familyName[],
-                                           const SkFontStyle&) const SK_OVERRIDE { return NULL; }
-    virtual SkTypeface* onMatchFaceStyle(const SkTypeface*,
-                                         const SkFontStyle&) const SK_OVERRIDE { return NULL; }
+                                           const SkFontStyle& style) const SK_OVERRIDE {
+        SkAutoTUnref<SkFontStyleSet> sset(this->matchFamily(familyName));
+        SkTypeface* face = sset.get() ? sset->matchStyle(style) : NULL;
+        if (NULL == face) {
+            // Fall back on fontconfig's own substitution, which finds a default
+            // font when nothing matches the family.
+            face = this->onLegacyCreateTypeface(familyName, to_legacy_style(style));
+        }
+        return face;
+    }
+
+    virtual SkTypeface* onMatchFamilyStyleCharacter(const char familyName[],
+                                                    const SkFontStyle& style,
+                                                    const char bpc47[],
+                                                    uint32_t character) const SK_OVERRIDE {
+        // Ask the interface first, since an installed one may be the only way
+        // to reach fonts, such as in a sandbox.
+        SkFontConfigInterface::FontIdentity identity;
+        SkString outFamilyName;
+        if (fFCI->matchCharacter(familyName, style, bpc47, character,
+                                 &identity, &outFamilyName)) {
+            return create_typeface(identity, outFamilyName);
+        }
+
+        FCLocker lock;
 
-    virtual SkTypeface* onCreateFromData(SkData*, int ttcIndex) const SK_OVERRIDE { return NULL; }
+        FcPattern* pattern = FcPatternCreate();
+        if (familyName) {
+            FcPatternAddString(pattern, FC_FAMILY, (FcChar8*)familyName);
+        }
+        add_fontconfig_style(pattern, style);
+
+        FcCharSet* charSet = FcCharSetCreate();
+        FcCharSetAddChar(charSet, character);
+        FcPatternAddCharSet(pattern, FC_CHARSET, charSet);
+        FcCharSetDestroy(charSet);
+
+        if (bpc47 && *bpc47) {
+            FcLangSet* langSet = FcLangSetCreate();
+            FcLangSetAdd(langSet, (const FcChar8*)bpc47);
+            FcPatternAddLangSet(pattern, FC_LANG, langSet);
+            FcLangSetDestroy(langSet);
+        }
+
+        FcConfigSubstitute(NULL, pattern, FcMatchPattern);
+        FcDefaultSubstitute(pattern);
+
+        FcResult result;
+        FcFontSet* font_set = FcFontSort(0, pattern, 0, 0, &result);
+        FcPatternDestroy(pattern);
+        if (!font_set) {
+            return NULL;
+        }
+
+        // The sort puts fonts that cover the character first, but does not
+        // drop those that don't, so check each in turn.
+        SkTypeface* face = NULL;
+        for (int i = 0; i < font_set->nfont && !face; ++i) {
+            FcPattern* match = font_set->fonts[i];
+            FcCharSet* matchCharSet;
+            if (!valid_pattern(match) ||
+                FcPatternGetCharSet(match, FC_CHARSET, 0, &matchCharSet) != FcResultMatch ||
+                !FcCharSetHasChar(matchCharSet, character)) {
+                continue;
+            }
+            int ttcIndex = get_int(match, FC_INDEX);
+            face = create_typeface(SkString(get_name(match, FC_FILE)),
+                                   SK_MinS32 == ttcIndex ? 0 : ttcIndex,
+                                   SkString(get_name(match, FC_FAMILY)),
+                                   make_fontconfig_style(match));
+        }
+        FcFontSetDestroy(font_set);
+        return face;
+    }
+
+    virtual SkTypeface* onMatchFaceStyle(const SkTypeface* face,
+                                         const SkFontStyle& style) const SK_OVERRIDE {
+        SkString familyName;
+        face->getFamilyName(&familyName);
+        return this->onMatchFamilyStyle(familyName.c_str(), style);
+    }
+
+    virtual SkTypeface* onCreateFromData(SkData* data, int ttcIndex) const SK_OVERRIDE {
+        SkAutoTUnref<SkStream> stream(SkNEW_ARGS(SkMemoryStream, (data)));
+        return this->createFromStream(stream, ttcIndex);
+    }
 
     virtual SkTypeface* onCreateFromStream(SkStream* stream, int ttcIndex) const SK_OVERRIDE {
         const size_t length = stream->getLength();
@@ -305,13 +514,15 @@ protected:
         }
 
         // TODO should the caller give us the style or should we get it from freetype?
+        SkString familyName;
         SkTypeface::Style style = SkTypeface::kNormal;
         bool isFixedWidth = false;
-        if (!find_name_and_attributes(stream, NULL, &style, &isFixedWidth)) {
+        if (!find_name_and_attributes(stream, ttcIndex, &familyName, &style, &isFixedWidth)) {
             return NULL;
         }
 
-        SkTypeface* face = FontConfigTypeface::Create(style, isFixedWidth, stream);
+        SkTypeface* face = FontConfigTypeface::Create(style, isFixedWidth, stream,
+                                                      ttcIndex, familyName);
         return face;
     }
 
@@ -0,0 +1,254 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use skia;
+
+use euclid::default::Size2D;
+use libc::{c_void, size_t};
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+/// An encoded image format, matching `SkImageDecoder::Format`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Bmp = 1,
+    Gif = 2,
+    Ico = 3,
+    Jpeg = 4,
+    Png = 5,
+    Wbmp = 6,
+    WebP = 7,
+    Pkm = 8,
+    Ktx = 9,
+}
+
+impl ImageFormat {
+    fn from_raw(format: i32) -> Option<ImageFormat> {
+        match format {
+            1 => Some(ImageFormat::Bmp),
+            2 => Some(ImageFormat::Gif),
+            3 => Some(ImageFormat::Ico),
+            4 => Some(ImageFormat::Jpeg),
+            5 => Some(ImageFormat::Png),
+            6 => Some(ImageFormat::Wbmp),
+            7 => Some(ImageFormat::WebP),
+            8 => Some(ImageFormat::Pkm),
+            9 => Some(ImageFormat::Ktx),
+            _ => None,
+        }
+    }
+}
+
+/// A format that `Image::encode` can produce, matching `SkImageEncoder::Type`.
+///
+/// JPEG, PNG and WebP need the crate feature of the same name; encoding to a
+/// format that was not built in fails.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodedFormat {
+    Jpeg = 4,
+    Png = 5,
+    WebP = 7,
+    Ktx = 8,
+}
+
+/// Why encoded data could not be turned into an `Image`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// No decoder in this build recognised the data. GIF, JPEG, PNG and WebP
+    /// are only recognised when the crate feature of the same name is on.
+    UnknownFormat,
+    /// The data looked like `ImageFormat` but could not be decoded.
+    Invalid(ImageFormat),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::UnknownFormat => write!(f, "unrecognised image format"),
+            DecodeError::Invalid(format) => write!(f, "invalid {:?} image data", format),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// A reference counted, immutable `SkImage`.
+pub struct Image {
+    image: skia::SkiaSkImageRef,
+    format: Option<ImageFormat>,
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkImageRelease(self.image);
+        }
+    }
+}
+
+impl Clone for Image {
+    fn clone(&self) -> Image {
+        unsafe {
+            skia::SkiaSkImageRetain(self.image);
+        }
+        Image {
+            image: self.image,
+            format: self.format,
+        }
+    }
+}
+
+// The contents of an SkImage never change and SkRefCnt is thread-safe.
+unsafe impl Send for Image {}
+unsafe impl Sync for Image {}
+
+impl Image {
+    /// Decodes a complete encoded image, such as the contents of a PNG file.
+    pub fn from_encoded(data: &[u8]) -> Result<Image, DecodeError> {
+        Image::decode(data, skia::SkiaSkImageCreateFromEncoded)
+    }
+
+    /// Keeps a copy of the encoded data and decodes it when the image is
+    /// first drawn. The pixels live in the global `DiscardableMemoryPool`, so
+    /// they may be purged while the image is not being drawn, and are then
+    /// decoded again on demand.
+    ///
+    /// Only the image header is read up front, so corrupt pixel data is not
+    /// reported here.
+    pub fn from_encoded_lazy(data: &[u8]) -> Result<Image, DecodeError> {
+        Image::decode(data, skia::SkiaSkImageCreateLazyFromEncoded)
+    }
+
+    fn decode(data: &[u8],
+              create: unsafe extern "C" fn(*const c_void, size_t, *mut i32) -> skia::SkiaSkImageRef)
+              -> Result<Image, DecodeError> {
+        let mut format = 0;
+        let image = unsafe {
+            create(data.as_ptr() as *const c_void, data.len() as size_t, &mut format)
+        };
+        let format = ImageFormat::from_raw(format).ok_or(DecodeError::UnknownFormat)?;
+        if image.is_null() {
+            return Err(DecodeError::Invalid(format));
+        }
+        Ok(Image {
+            image,
+            format: Some(format),
+        })
+    }
+
+    /// Takes ownership of a reference to an image created by the Skia C API.
+    ///
+    /// # Safety
+    ///
+    /// `image` must be a valid `SkImage` whose reference is not released
+    /// elsewhere.
+    pub unsafe fn from_raw(image: skia::SkiaSkImageRef) -> Image {
+        Image {
+            image,
+            format: None,
+        }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkImageRef {
+        self.image
+    }
+
+    /// The format this image was decoded from, if any.
+    pub fn format(&self) -> Option<ImageFormat> {
+        self.format
+    }
+
+    pub fn width(&self) -> i32 {
+        unsafe {
+            skia::SkiaSkImageGetWidth(self.image)
+        }
+    }
+
+    pub fn height(&self) -> i32 {
+        unsafe {
+            skia::SkiaSkImageGetHeight(self.image)
+        }
+    }
+
+    pub fn size(&self) -> Size2D<i32> {
+        Size2D::new(self.width(), self.height())
+    }
+
+    /// Encodes the image's pixels into `writer`. `quality` runs from 0 to 100
+    /// and is ignored by lossless formats. GPU-backed images are read back
+    /// first.
+    pub fn encode<W: Write>(&self,
+                            format: EncodedFormat,
+                            quality: u8,
+                            writer: &mut W)
+                            -> io::Result<()> {
+        let mut sink = EncodeSink {
+            writer,
+            error: None,
+            panic: None,
+        };
+        let encoded = unsafe {
+            skia::SkiaSkImageEncode(self.image,
+                                    format as i32,
+                                    quality.min(100) as i32,
+                                    write_to_sink::<W>,
+                                    &mut sink as *mut EncodeSink<W> as *mut c_void)
+        };
+        if let Some(payload) = sink.panic {
+            panic::resume_unwind(payload);
+        }
+        if let Some(error) = sink.error {
+            return Err(error);
+        }
+        if !encoded {
+            return Err(io::Error::other(format!("could not encode image as {:?}", format)));
+        }
+        Ok(())
+    }
+
+    pub fn encode_to_vec(&self, format: EncodedFormat, quality: u8) -> io::Result<Vec<u8>> {
+        let mut result = Vec::new();
+        self.encode(format, quality, &mut result)?;
+        Ok(result)
+    }
+}
+
+/// The closure handed to `SkiaSkImageEncode`, keeping the first error the
+/// writer returns so that it can be reported instead of a generic failure.
+/// A panic in the writer is kept too and resumed once Skia has returned,
+/// since unwinding through Skia is undefined.
+struct EncodeSink<'a, W: Write + 'a> {
+    writer: &'a mut W,
+    error: Option<io::Error>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+extern "C" fn write_to_sink<W: Write>(closure: *mut c_void,
+                                      data: *const c_void,
+                                      length: size_t)
+                                      -> bool {
+    if length == 0 {
+        return true;
+    }
+    let sink = unsafe { &mut *(closure as *mut EncodeSink<W>) };
+    let data = unsafe { slice::from_raw_parts(data as *const u8, length) };
+    let writer = &mut sink.writer;
+    match panic::catch_unwind(AssertUnwindSafe(|| writer.write_all(data))) {
+        Ok(Ok(())) => true,
+        Ok(Err(error)) => {
+            sink.error = Some(error);
+            false
+        }
+        Err(payload) => {
+            sink.panic = Some(payload);
+            false
+        }
+    }
+}
@@ -0,0 +1,482 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Image filters, which can be chained into a graph whose nodes map one to
+//! one onto SVG `<filter>` primitives.
+//!
+//! Each node reads from `FilterInput`s and produces a new `ImageFilter`,
+//! which can in turn be the input of later nodes. The last node is applied
+//! with `Paint::set_image_filter`, either on the paint of a draw or on the
+//! paint passed to `Canvas::save_layer`.
+
+use blend_mode::{BlendMode, Xfermode};
+use color::Color;
+use color_filter::ColorFilter;
+use skia;
+
+use euclid::default::{Point3D, Rect, Size2D, Vector2D, Vector3D};
+use std::ptr;
+
+/// Where a node reads its pixels from.
+#[derive(Clone, Copy)]
+pub enum FilterInput<'a> {
+    /// The content being filtered, SVG `SourceGraphic`.
+    SourceGraphic,
+    /// The alpha channel of the content in black, SVG `SourceAlpha`.
+    SourceAlpha,
+    /// The result of another node.
+    Filter(&'a ImageFilter),
+}
+
+impl<'a> From<&'a ImageFilter> for FilterInput<'a> {
+    fn from(filter: &'a ImageFilter) -> FilterInput<'a> {
+        FilterInput::Filter(filter)
+    }
+}
+
+impl<'a> FilterInput<'a> {
+    /// Returns the filter to pass to Skia, where `None` is the source
+    /// graphic.
+    fn to_filter(self) -> Option<ImageFilter> {
+        match self {
+            FilterInput::SourceGraphic => None,
+            FilterInput::SourceAlpha => {
+                ImageFilter::from_raw(unsafe { skia::SkiaSkImageFilterCreateSourceAlpha() })
+            }
+            FilterInput::Filter(filter) => Some(filter.clone()),
+        }
+    }
+}
+
+fn as_raw(filter: &Option<ImageFilter>) -> skia::SkiaSkImageFilterRef {
+    filter.as_ref().map_or(ptr::null_mut(), ImageFilter::as_raw)
+}
+
+/// Limits the output of a node, as the `x`, `y`, `width` and `height` of an
+/// SVG filter primitive do. Only the edges that are set are applied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CropRect {
+    pub rect: Rect<f32>,
+    pub edges: u32,
+}
+
+impl CropRect {
+    pub const LEFT: u32 = 0x01;
+    pub const TOP: u32 = 0x02;
+    pub const RIGHT: u32 = 0x04;
+    pub const BOTTOM: u32 = 0x08;
+    pub const ALL: u32 = 0x0f;
+
+    /// Crops to every edge of `rect`.
+    pub fn new(rect: Rect<f32>) -> CropRect {
+        CropRect {
+            rect,
+            edges: CropRect::ALL,
+        }
+    }
+}
+
+fn crop_rect(crop_rect: Option<&CropRect>) -> Option<skia::SkiaCropRect> {
+    crop_rect.map(|crop_rect| {
+        skia::SkiaCropRect {
+            rect: crop_rect.rect.into(),
+            edges: crop_rect.edges,
+        }
+    })
+}
+
+fn crop_rect_ptr(crop_rect: &Option<skia::SkiaCropRect>) -> *const skia::SkiaCropRect {
+    crop_rect.as_ref().map_or(ptr::null(), |crop_rect| crop_rect as *const _)
+}
+
+/// The channel of the displacement image that moves pixels along an axis,
+/// matching `SkDisplacementMapEffect::ChannelSelectorType`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChannel {
+    R = 1,
+    G = 2,
+    B = 3,
+    A = 4,
+}
+
+/// How a matrix convolution reads pixels outside the input, matching
+/// `SkMatrixConvolutionImageFilter::TileMode`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvolutionTileMode {
+    /// SVG `edgeMode="duplicate"`.
+    Clamp = 0,
+    /// SVG `edgeMode="wrap"`.
+    Repeat = 1,
+    /// SVG `edgeMode="none"`.
+    ClampToBlack = 2,
+}
+
+/// The kernel of a matrix convolution, as the attributes of SVG
+/// `feConvolveMatrix` describe it.
+#[derive(Clone, Copy, Debug)]
+pub struct ConvolutionKernel<'a> {
+    pub size: Size2D<i32>,
+    /// `size.width * size.height` values in row order, as SVG's
+    /// `kernelMatrix` lists them.
+    pub values: &'a [f32],
+    /// Divides the result; must not be zero.
+    pub divisor: f32,
+    /// Added to the result after dividing, in 0..1 units as in SVG.
+    pub bias: f32,
+    /// The kernel cell over the output pixel; must lie inside the kernel.
+    pub target: Vector2D<i32>,
+}
+
+/// The light source of a lighting node, as SVG `feDistantLight`,
+/// `fePointLight` and `feSpotLight` describe it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Light {
+    /// Light from infinitely far away, travelling along `direction`.
+    Distant {
+        direction: Vector3D<f32>,
+        color: Color,
+    },
+    Point {
+        location: Point3D<f32>,
+        color: Color,
+    },
+    /// A cone of light from `location` towards `target`. `cutoff_angle` is
+    /// in degrees.
+    Spot {
+        location: Point3D<f32>,
+        target: Point3D<f32>,
+        specular_exponent: f32,
+        cutoff_angle: f32,
+        color: Color,
+    },
+}
+
+impl Light {
+    fn to_raw(self) -> skia::SkiaLight {
+        let mut light = skia::SkiaLight::default();
+        match self {
+            Light::Distant { direction, color } => {
+                light.direction = direction.into();
+                light.color = color.0;
+            }
+            Light::Point { location, color } => {
+                light.type_ = 1;
+                light.location = location.into();
+                light.color = color.0;
+            }
+            Light::Spot { location, target, specular_exponent, cutoff_angle, color } => {
+                light.type_ = 2;
+                light.location = location.into();
+                light.target = target.into();
+                light.specular_exponent = specular_exponent;
+                light.cutoff_angle = cutoff_angle;
+                light.color = color.0;
+            }
+        }
+        light
+    }
+}
+
+/// A reference counted `SkImageFilter`: one node of a filter graph, together
+/// with all of its inputs.
+pub struct ImageFilter {
+    image_filter: skia::SkiaSkImageFilterRef,
+}
+
+impl Drop for ImageFilter {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkImageFilterRelease(self.image_filter);
+        }
+    }
+}
+
+impl Clone for ImageFilter {
+    fn clone(&self) -> ImageFilter {
+        unsafe {
+            skia::SkiaSkImageFilterRetain(self.image_filter);
+        }
+        ImageFilter {
+            image_filter: self.image_filter,
+        }
+    }
+}
+
+// Image filters are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for ImageFilter {}
+unsafe impl Sync for ImageFilter {}
+
+impl ImageFilter {
+    fn from_raw(image_filter: skia::SkiaSkImageFilterRef) -> Option<ImageFilter> {
+        if image_filter.is_null() {
+            return None;
+        }
+        Some(ImageFilter { image_filter })
+    }
+
+    /// SVG `feGaussianBlur`. The standard deviations must not be negative.
+    pub fn blur(sigma: Size2D<f32>,
+                input: FilterInput,
+                crop: Option<&CropRect>)
+                -> Option<ImageFilter> {
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateBlur(sigma.width,
+                                              sigma.height,
+                                              as_raw(&input),
+                                              crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feDropShadow`: the input drawn over a blurred, offset and
+    /// coloured copy of its alpha.
+    pub fn drop_shadow(offset: Vector2D<f32>,
+                       sigma: Size2D<f32>,
+                       color: Color,
+                       input: FilterInput,
+                       crop: Option<&CropRect>)
+                       -> Option<ImageFilter> {
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateDropShadow(offset.x,
+                                                    offset.y,
+                                                    sigma.width,
+                                                    sigma.height,
+                                                    color.0,
+                                                    as_raw(&input),
+                                                    crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feMorphology` with `operator="dilate"`.
+    pub fn dilate(radius: Size2D<i32>,
+                  input: FilterInput,
+                  crop: Option<&CropRect>)
+                  -> Option<ImageFilter> {
+        ImageFilter::morphology(true, radius, input, crop)
+    }
+
+    /// SVG `feMorphology` with `operator="erode"`.
+    pub fn erode(radius: Size2D<i32>,
+                 input: FilterInput,
+                 crop: Option<&CropRect>)
+                 -> Option<ImageFilter> {
+        ImageFilter::morphology(false, radius, input, crop)
+    }
+
+    fn morphology(dilate: bool,
+                  radius: Size2D<i32>,
+                  input: FilterInput,
+                  crop: Option<&CropRect>)
+                  -> Option<ImageFilter> {
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateMorphology(dilate,
+                                                    radius.width,
+                                                    radius.height,
+                                                    as_raw(&input),
+                                                    crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feDisplacementMap`: moves the pixels of `color` by the channels
+    /// of `displacement`.
+    pub fn displacement_map(x_channel: ColorChannel,
+                            y_channel: ColorChannel,
+                            scale: f32,
+                            displacement: FilterInput,
+                            color: FilterInput,
+                            crop: Option<&CropRect>)
+                            -> Option<ImageFilter> {
+        let (displacement, color) = (displacement.to_filter(), color.to_filter());
+        let crop = crop_rect(crop);
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateDisplacementMap(x_channel as i32,
+                                                         y_channel as i32,
+                                                         scale,
+                                                         as_raw(&displacement),
+                                                         as_raw(&color),
+                                                         crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feDiffuseLighting`, lighting the input's alpha as a bump map.
+    pub fn diffuse_lighting(light: &Light,
+                            surface_scale: f32,
+                            diffuse_constant: f32,
+                            input: FilterInput,
+                            crop: Option<&CropRect>)
+                            -> Option<ImageFilter> {
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        let light = light.to_raw();
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateDiffuseLighting(&light,
+                                                         surface_scale,
+                                                         diffuse_constant,
+                                                         as_raw(&input),
+                                                         crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feSpecularLighting`, lighting the input's alpha as a bump map.
+    pub fn specular_lighting(light: &Light,
+                             surface_scale: f32,
+                             specular_constant: f32,
+                             specular_exponent: f32,
+                             input: FilterInput,
+                             crop: Option<&CropRect>)
+                             -> Option<ImageFilter> {
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        let light = light.to_raw();
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateSpecularLighting(&light,
+                                                          surface_scale,
+                                                          specular_constant,
+                                                          specular_exponent,
+                                                          as_raw(&input),
+                                                          crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feConvolveMatrix`.
+    pub fn matrix_convolution(kernel: &ConvolutionKernel,
+                              tile_mode: ConvolutionTileMode,
+                              convolve_alpha: bool,
+                              input: FilterInput,
+                              crop: Option<&CropRect>)
+                              -> Option<ImageFilter> {
+        let size = kernel.size;
+        if size.width < 1 || size.height < 1 || kernel.divisor == 0.0 ||
+           kernel.values.len() != size.width as usize * size.height as usize {
+            return None;
+        }
+        // SVG applies the kernel rotated by 180° and Skia applies it as
+        // given, so reverse it. The target is in output terms and stays put.
+        let values: Vec<f32> = kernel.values.iter().rev().cloned().collect();
+        // Skia adds the bias to sums in 0..255 units.
+        let bias = kernel.bias * 255.0;
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateMatrixConvolution(size.width,
+                                                           size.height,
+                                                           values.as_ptr(),
+                                                           1.0 / kernel.divisor,
+                                                           bias,
+                                                           kernel.target.x,
+                                                           kernel.target.y,
+                                                           tile_mode as i32,
+                                                           convolve_alpha,
+                                                           as_raw(&input),
+                                                           crop_rect_ptr(&crop))
+        })
+    }
+
+    /// Applies `color_filter`, as SVG `feColorMatrix` and
+    /// `feComponentTransfer` do.
+    pub fn color_filter(color_filter: &ColorFilter,
+                        input: FilterInput,
+                        crop: Option<&CropRect>)
+                        -> Option<ImageFilter> {
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateColorFilter(color_filter.as_raw(),
+                                                     as_raw(&input),
+                                                     crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feMerge`: draws the inputs in order, each with source-over, or
+    /// with the matching entry of `modes` if it is given.
+    pub fn merge(inputs: &[FilterInput],
+                 modes: Option<&[BlendMode]>,
+                 crop: Option<&CropRect>)
+                 -> Option<ImageFilter> {
+        if modes.is_some_and(|modes| modes.len() != inputs.len()) {
+            return None;
+        }
+        let filters: Vec<Option<ImageFilter>> =
+            inputs.iter().map(|input| input.to_filter()).collect();
+        let raw_filters: Vec<skia::SkiaSkImageFilterRef> = filters.iter().map(as_raw).collect();
+        let modes: Option<Vec<i32>> =
+            modes.map(|modes| modes.iter().map(|&mode| mode as i32).collect());
+        let crop = crop_rect(crop);
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateMerge(raw_filters.as_ptr(),
+                                               modes.as_ref().map_or(ptr::null(), |m| m.as_ptr()),
+                                               raw_filters.len() as i32,
+                                               crop_rect_ptr(&crop))
+        })
+    }
+
+    /// Feeds the result of `inner` into `outer`, in place of the source
+    /// graphic.
+    pub fn compose(outer: &ImageFilter, inner: &ImageFilter) -> Option<ImageFilter> {
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateCompose(outer.as_raw(), inner.as_raw())
+        })
+    }
+
+    /// SVG `feOffset`.
+    pub fn offset(offset: Vector2D<f32>,
+                  input: FilterInput,
+                  crop: Option<&CropRect>)
+                  -> Option<ImageFilter> {
+        let (input, crop) = (input.to_filter(), crop_rect(crop));
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateOffset(offset.x,
+                                                offset.y,
+                                                as_raw(&input),
+                                                crop_rect_ptr(&crop))
+        })
+    }
+
+    /// SVG `feTile`: repeats the `src` region of the input to fill `dst`.
+    pub fn tile(src: &Rect<f32>, dst: &Rect<f32>, input: FilterInput) -> Option<ImageFilter> {
+        let input = input.to_filter();
+        let (src, dst) = (skia::SkiaRect::from(*src), skia::SkiaRect::from(*dst));
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateTile(&src, &dst, as_raw(&input))
+        })
+    }
+
+    /// SVG `feBlend` and the Porter-Duff operators of `feComposite`: draws
+    /// `foreground` over `background` with `mode`.
+    pub fn blend(mode: BlendMode,
+                 background: FilterInput,
+                 foreground: FilterInput,
+                 crop: Option<&CropRect>)
+                 -> Option<ImageFilter> {
+        let (background, foreground) = (background.to_filter(), foreground.to_filter());
+        let crop = crop_rect(crop);
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateBlend(mode as i32,
+                                               as_raw(&background),
+                                               as_raw(&foreground),
+                                               crop_rect_ptr(&crop))
+        })
+    }
+
+    /// Like `blend`, with a custom transfer mode, such as
+    /// `Xfermode::arithmetic` for SVG `feComposite operator="arithmetic"`.
+    pub fn xfermode(xfermode: &Xfermode,
+                    background: FilterInput,
+                    foreground: FilterInput,
+                    crop: Option<&CropRect>)
+                    -> Option<ImageFilter> {
+        let (background, foreground) = (background.to_filter(), foreground.to_filter());
+        let crop = crop_rect(crop);
+        ImageFilter::from_raw(unsafe {
+            skia::SkiaSkImageFilterCreateXfermode(xfermode.as_raw(),
+                                                  as_raw(&background),
+                                                  as_raw(&foreground),
+                                                  crop_rect_ptr(&crop))
+        })
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkImageFilterRef {
+        self.image_filter
+    }
+}
@@ -0,0 +1,109 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use image::Image;
+use image_info::ImageInfo;
+use skia;
+
+use libc::{c_void, size_t};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+/// A source of pixels that Skia pulls from only when an image is drawn,
+/// bridged to an `SkImageGenerator`.
+///
+/// The pixels are kept in the global `DiscardableMemoryPool`, so
+/// `get_pixels()` is called again whenever they have been purged, possibly
+/// from whichever thread is drawing. A panic in a method is caught and
+/// treated as a failure.
+pub trait ImageGenerator: Send + Sync {
+    /// The dimensions and format of the generated pixels. This must not
+    /// change over the life of the generator.
+    fn info(&self) -> Option<ImageInfo>;
+
+    /// The encoded form of the image, if there is one, for consumers such as
+    /// PDF output that would rather embed it than the decoded pixels.
+    fn encoded_data(&self) -> Option<&[u8]> {
+        None
+    }
+
+    /// Writes pixels described by `info` into `dst`, starting each row
+    /// `row_bytes` after the previous one. Returns false on failure.
+    fn get_pixels(&self, info: &ImageInfo, dst: &mut [u8], row_bytes: usize) -> bool;
+}
+
+impl Image {
+    /// An image whose pixels come from `generator`. Returns `None` if the
+    /// generator has no valid `info()`.
+    pub fn from_generator<G: ImageGenerator + 'static>(generator: G) -> Option<Image> {
+        let callbacks = skia::SkiaImageGeneratorCallbacks {
+            get_info: get_info::<G>,
+            get_encoded_data: get_encoded_data::<G>,
+            get_pixels: get_pixels::<G>,
+            destroy: destroy::<G>,
+        };
+        // Skia owns the generator from here on and calls `destroy` when it is
+        // done with it, including when the image cannot be created.
+        let closure = Box::into_raw(Box::new(generator)) as *mut c_void;
+        let image = unsafe { skia::SkiaSkImageCreateFromGenerator(&callbacks, closure) };
+        if image.is_null() {
+            return None;
+        }
+        Some(unsafe { Image::from_raw(image) })
+    }
+}
+
+extern "C" fn get_info<G: ImageGenerator>(closure: *mut c_void,
+                                          info: *mut skia::SkiaImageInfo)
+                                          -> bool {
+    let generator = unsafe { &*(closure as *const G) };
+    // Unwinding into Skia is undefined, so a panic is treated as a failure.
+    match panic::catch_unwind(AssertUnwindSafe(|| generator.info())) {
+        Ok(Some(result)) => {
+            unsafe {
+                *info = result.into();
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+extern "C" fn get_encoded_data<G: ImageGenerator>(closure: *mut c_void,
+                                                  length: *mut size_t)
+                                                  -> *const c_void {
+    let generator = unsafe { &*(closure as *const G) };
+    match panic::catch_unwind(AssertUnwindSafe(|| generator.encoded_data())) {
+        Ok(Some(data)) => {
+            unsafe {
+                *length = data.len() as size_t;
+            }
+            data.as_ptr() as *const c_void
+        }
+        _ => ptr::null(),
+    }
+}
+
+extern "C" fn get_pixels<G: ImageGenerator>(closure: *mut c_void,
+                                            info: *const skia::SkiaImageInfo,
+                                            pixels: *mut c_void,
+                                            row_bytes: size_t)
+                                            -> bool {
+    let generator = unsafe { &*(closure as *const G) };
+    let info = ImageInfo::from(unsafe { *info });
+    let length = info.compute_byte_size(row_bytes);
+    let dst: &mut [u8] = if length == 0 || pixels.is_null() {
+        &mut []
+    } else {
+        unsafe { slice::from_raw_parts_mut(pixels as *mut u8, length) }
+    };
+    panic::catch_unwind(AssertUnwindSafe(|| generator.get_pixels(&info, dst, row_bytes)))
+        .unwrap_or(false)
+}
+
+extern "C" fn destroy<G: ImageGenerator>(closure: *mut c_void) {
+    let generator = unsafe { Box::from_raw(closure as *mut G) };
+    let _ = panic::catch_unwind(AssertUnwindSafe(move || drop(generator)));
+}
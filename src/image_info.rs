@@ -0,0 +1,144 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use skia;
+
+use euclid::default::Size2D;
+
+/// How the bits of a pixel are laid out, matching `SkColorType`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorType {
+    Unknown = 0,
+    Alpha8 = 1,
+    Rgb565 = 2,
+    Argb4444 = 3,
+    Rgba8888 = 4,
+    Bgra8888 = 5,
+    Index8 = 6,
+}
+
+impl ColorType {
+    /// The native 32-bit format, which this crate builds with BGRA byte order
+    /// (`SK_R32_SHIFT=16`).
+    pub const N32: ColorType = ColorType::Bgra8888;
+
+    fn from_raw(color_type: i32) -> ColorType {
+        match color_type {
+            1 => ColorType::Alpha8,
+            2 => ColorType::Rgb565,
+            3 => ColorType::Argb4444,
+            4 => ColorType::Rgba8888,
+            5 => ColorType::Bgra8888,
+            6 => ColorType::Index8,
+            _ => ColorType::Unknown,
+        }
+    }
+
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            ColorType::Unknown => 0,
+            ColorType::Alpha8 | ColorType::Index8 => 1,
+            ColorType::Rgb565 | ColorType::Argb4444 => 2,
+            ColorType::Rgba8888 | ColorType::Bgra8888 => 4,
+        }
+    }
+}
+
+/// How the alpha channel of a pixel is interpreted, matching `SkAlphaType`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlphaType {
+    /// There is alpha storage, but its values should be ignored.
+    Ignore = 0,
+    Opaque = 1,
+    /// The colour channels have already been multiplied by alpha.
+    Premul = 2,
+    Unpremul = 3,
+}
+
+impl AlphaType {
+    fn from_raw(alpha_type: i32) -> AlphaType {
+        match alpha_type {
+            0 => AlphaType::Ignore,
+            1 => AlphaType::Opaque,
+            3 => AlphaType::Unpremul,
+            _ => AlphaType::Premul,
+        }
+    }
+}
+
+/// The dimensions and pixel format of a block of pixels, matching
+/// `SkImageInfo`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub width: i32,
+    pub height: i32,
+    pub color_type: ColorType,
+    pub alpha_type: AlphaType,
+}
+
+impl ImageInfo {
+    pub fn new(size: Size2D<i32>, color_type: ColorType, alpha_type: AlphaType) -> ImageInfo {
+        ImageInfo {
+            width: size.width,
+            height: size.height,
+            color_type,
+            alpha_type,
+        }
+    }
+
+    /// Premultiplied pixels in the native 32-bit format.
+    pub fn n32_premul(size: Size2D<i32>) -> ImageInfo {
+        ImageInfo::new(size, ColorType::N32, AlphaType::Premul)
+    }
+
+    pub fn size(&self) -> Size2D<i32> {
+        Size2D::new(self.width, self.height)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    pub fn bytes_per_pixel(&self) -> usize {
+        self.color_type.bytes_per_pixel()
+    }
+
+    /// The length of a row with no padding.
+    pub fn min_row_bytes(&self) -> usize {
+        self.width.max(0) as usize * self.bytes_per_pixel()
+    }
+
+    /// The number of bytes needed to hold the pixels with rows `row_bytes`
+    /// apart. The last row only needs `min_row_bytes()`.
+    pub fn compute_byte_size(&self, row_bytes: usize) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        (self.height as usize - 1) * row_bytes + self.min_row_bytes()
+    }
+}
+
+impl From<ImageInfo> for skia::SkiaImageInfo {
+    fn from(info: ImageInfo) -> skia::SkiaImageInfo {
+        skia::SkiaImageInfo {
+            width: info.width,
+            height: info.height,
+            color_type: info.color_type as i32,
+            alpha_type: info.alpha_type as i32,
+        }
+    }
+}
+
+impl From<skia::SkiaImageInfo> for ImageInfo {
+    fn from(info: skia::SkiaImageInfo) -> ImageInfo {
+        ImageInfo {
+            width: info.width,
+            height: info.height,
+            color_type: ColorType::from_raw(info.color_type),
+            alpha_type: AlphaType::from_raw(info.alpha_type),
+        }
+    }
+}
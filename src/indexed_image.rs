@@ -0,0 +1,122 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use color::Color;
+use image::Image;
+use skia;
+
+use euclid::default::Size2D;
+use libc::{c_void, size_t};
+
+/// An `kIndex_8` image: one byte per pixel, looked up in a palette of up to
+/// 256 colours.
+///
+/// On the GPU the indices are uploaded as-is where
+/// `GrContext::supportsIndex8PixelConfig` allows it, and expanded otherwise.
+pub struct IndexedImage {
+    indices: skia::SkiaSkDataRef,
+    size: Size2D<i32>,
+    row_bytes: usize,
+    image: Image,
+}
+
+impl Drop for IndexedImage {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkDataRelease(self.indices);
+        }
+    }
+}
+
+// The indices are never written after creation, and `SkData` is refcounted
+// atomically.
+unsafe impl Send for IndexedImage {}
+unsafe impl Sync for IndexedImage {}
+
+impl IndexedImage {
+    /// Copies `indices`, whose rows are `row_bytes` apart, and pairs them
+    /// with `palette`.
+    ///
+    /// Indices past the end of the palette draw as transparent black.
+    /// Returns `None` if the size is empty, `indices` is too short, or the
+    /// palette is empty or has more than 256 colours.
+    pub fn new(size: Size2D<i32>,
+               indices: &[u8],
+               row_bytes: usize,
+               palette: &[Color])
+               -> Option<IndexedImage> {
+        if size.width <= 0 || size.height <= 0 || row_bytes < size.width as usize ||
+           indices.len() < row_bytes * (size.height as usize - 1) + size.width as usize {
+            return None;
+        }
+        let indices = unsafe {
+            skia::SkiaSkDataCreateWithCopy(indices.as_ptr() as *const c_void,
+                                           indices.len() as size_t)
+        };
+        let image = match create_image(indices, size, row_bytes, palette) {
+            Some(image) => image,
+            None => {
+                unsafe {
+                    skia::SkiaSkDataRelease(indices);
+                }
+                return None;
+            }
+        };
+        Some(IndexedImage {
+            indices,
+            size,
+            row_bytes,
+            image,
+        })
+    }
+
+    /// Returns the image with the current palette.
+    ///
+    /// Images are immutable, so one returned before `set_palette` keeps
+    /// drawing with the old colours.
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    pub fn size(&self) -> Size2D<i32> {
+        self.size
+    }
+
+    /// Replaces the palette without copying the indices, for cheap palette
+    /// animation. Returns `false`, keeping the old palette, if `palette` is
+    /// empty or has more than 256 colours.
+    pub fn set_palette(&mut self, palette: &[Color]) -> bool {
+        match create_image(self.indices, self.size, self.row_bytes, palette) {
+            Some(image) => {
+                self.image = image;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn create_image(indices: skia::SkiaSkDataRef,
+                size: Size2D<i32>,
+                row_bytes: usize,
+                palette: &[Color])
+                -> Option<Image> {
+    if palette.is_empty() || palette.len() > 256 {
+        return None;
+    }
+    let palette: Vec<u32> = palette.iter().map(|color| color.0).collect();
+    unsafe {
+        let image = skia::SkiaSkImageCreateIndex8(size.width,
+                                                  size.height,
+                                                  indices,
+                                                  row_bytes as size_t,
+                                                  palette.as_ptr(),
+                                                  palette.len() as i32);
+        if image.is_null() {
+            None
+        } else {
+            Some(Image::from_raw(image))
+        }
+    }
+}
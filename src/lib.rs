@@ -38,9 +38,42 @@ pub use skia::{
     SkiaGrContextRelease,
 };
 
+pub mod blend_mode;
+pub mod camera;
+pub mod canvas;
+pub mod color;
+pub mod color_filter;
+pub mod compressed_texture;
+pub mod discardable_memory_pool;
+pub mod draw_looper;
 pub mod gl_context;
 pub mod gl_rasterization_context;
+pub mod image;
+pub mod image_filter;
+pub mod image_generator;
+pub mod image_info;
+pub mod indexed_image;
+pub mod mask_filter;
+pub mod matrix;
+pub mod matrix44;
+pub mod movie;
+pub mod paint;
+pub mod path;
+pub mod path_effect;
+pub mod path_measure;
+pub mod picture;
+pub mod pixmap;
+pub mod region;
+pub mod resize;
+pub mod rrect;
+pub mod shader;
 pub mod skia;
+pub mod stroke;
+pub mod surface;
+pub mod typeface;
+
+#[cfg(any(target_os="linux", target_os="android"))]
+pub mod font_config;
 
 #[cfg(target_os="linux")]
 pub mod gl_context_glx;
@@ -0,0 +1,122 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use skia;
+
+use euclid::default::Vector3D;
+
+/// Which side of the edge a blur covers, matching `SkBlurStyle`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlurStyle {
+    /// Blurred inside and outside, as for `box-shadow` and `text-shadow`.
+    Normal = 0,
+    /// Solid inside, blurred outside.
+    Solid = 1,
+    /// Nothing inside, blurred outside.
+    Outer = 2,
+    /// Blurred inside, nothing outside, as for inset `box-shadow`.
+    Inner = 3,
+}
+
+/// Converts a CSS shadow blur radius to the standard deviation of the
+/// Gaussian it describes, which the CSS Backgrounds spec defines as half the
+/// radius.
+pub fn blur_radius_to_sigma(radius: f32) -> f32 {
+    radius.max(0.0) / 2.0
+}
+
+/// A reference counted `SkMaskFilter`, which alters the coverage of geometry
+/// before it is coloured.
+pub struct MaskFilter {
+    mask_filter: skia::SkiaSkMaskFilterRef,
+}
+
+impl Drop for MaskFilter {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkMaskFilterRelease(self.mask_filter);
+        }
+    }
+}
+
+impl Clone for MaskFilter {
+    fn clone(&self) -> MaskFilter {
+        unsafe {
+            skia::SkiaSkMaskFilterRetain(self.mask_filter);
+        }
+        MaskFilter {
+            mask_filter: self.mask_filter,
+        }
+    }
+}
+
+// Mask filters are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for MaskFilter {}
+unsafe impl Sync for MaskFilter {}
+
+impl MaskFilter {
+    /// A flag for `blur`: the blur is not scaled by the canvas transform.
+    pub const BLUR_IGNORE_TRANSFORM: u32 = 0x01;
+    /// A flag for `blur`: uses a slower, closer approximation of a Gaussian.
+    pub const BLUR_HIGH_QUALITY: u32 = 0x02;
+
+    fn from_raw(mask_filter: skia::SkiaSkMaskFilterRef) -> Option<MaskFilter> {
+        if mask_filter.is_null() {
+            return None;
+        }
+        Some(MaskFilter { mask_filter })
+    }
+
+    /// Blurs the coverage with a Gaussian of standard deviation `sigma`.
+    /// `flags` combine the `BLUR_*` constants. Returns `None` unless `sigma`
+    /// is positive, since a zero blur needs no filter.
+    pub fn blur(style: BlurStyle, sigma: f32, flags: u32) -> Option<MaskFilter> {
+        MaskFilter::from_raw(unsafe {
+            skia::SkiaSkMaskFilterCreateBlur(style as i32, sigma, flags)
+        })
+    }
+
+    /// Lights the blurred coverage as a bump map, for a raised look.
+    /// `ambient` is from 0 to 1 and `specular` from 0 to 16.
+    pub fn emboss(sigma: f32,
+                  direction: Vector3D<f32>,
+                  ambient: f32,
+                  specular: f32)
+                  -> Option<MaskFilter> {
+        let direction = [direction.x, direction.y, direction.z];
+        MaskFilter::from_raw(unsafe {
+            skia::SkiaSkMaskFilterCreateEmboss(sigma, direction.as_ptr(), ambient, specular)
+        })
+    }
+
+    /// Maps each coverage value through `table`.
+    pub fn table(table: &[u8; 256]) -> MaskFilter {
+        MaskFilter::from_raw(unsafe { skia::SkiaSkMaskFilterCreateTable(table.as_ptr()) })
+            .expect("SkTableMaskFilter::Create failed")
+    }
+
+    /// Raises coverage to the power `gamma`.
+    pub fn gamma(gamma: f32) -> MaskFilter {
+        MaskFilter::from_raw(unsafe { skia::SkiaSkMaskFilterCreateGamma(gamma) })
+            .expect("SkTableMaskFilter::CreateGamma failed")
+    }
+
+    /// Drops coverage up to `min` and saturates it from `max`, stretching
+    /// the values between.
+    pub fn clip(min: u8, max: u8) -> MaskFilter {
+        MaskFilter::from_raw(unsafe { skia::SkiaSkMaskFilterCreateClip(min, max) })
+            .expect("SkTableMaskFilter::CreateClip failed")
+    }
+
+    /// Keeps coverage only on alternate pixels, in a checkerboard.
+    pub fn stipple() -> MaskFilter {
+        MaskFilter::from_raw(unsafe { skia::SkiaSkMaskFilterCreateStipple() })
+            .expect("SkStippleMaskFilter::Create failed")
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkMaskFilterRef {
+        self.mask_filter
+    }
+}
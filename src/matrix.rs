@@ -0,0 +1,93 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use skia;
+
+use euclid::default::{Point2D, Transform2D};
+
+/// A 3x3 `SkMatrix`. Unlike `Transform2D`, it can hold a perspective
+/// projection, such as one flattened from a `Matrix44` or a `View3D`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix {
+    matrix: skia::SkiaMatrix,
+}
+
+impl Default for Matrix {
+    fn default() -> Matrix {
+        Matrix::identity()
+    }
+}
+
+impl From<Transform2D<f32>> for Matrix {
+    fn from(transform: Transform2D<f32>) -> Matrix {
+        Matrix {
+            matrix: transform.into(),
+        }
+    }
+}
+
+impl Matrix {
+    pub fn identity() -> Matrix {
+        Matrix::from_values([1.0, 0.0, 0.0,
+                             0.0, 1.0, 0.0,
+                             0.0, 0.0, 1.0])
+    }
+
+    /// `values` are in `SkMatrix` order: scale x, skew x, translate x,
+    /// skew y, scale y, translate y, then the three perspective terms.
+    pub fn from_values(values: [f32; 9]) -> Matrix {
+        Matrix {
+            matrix: skia::SkiaMatrix { values },
+        }
+    }
+
+    pub fn values(&self) -> [f32; 9] {
+        self.matrix.values
+    }
+
+    pub fn as_raw(&self) -> &skia::SkiaMatrix {
+        &self.matrix
+    }
+
+    pub fn has_perspective(&self) -> bool {
+        let values = &self.matrix.values;
+        values[6] != 0.0 || values[7] != 0.0 || values[8] != 1.0
+    }
+
+    /// The matrix as an affine transform, or `None` if it has perspective.
+    pub fn to_transform_2d(&self) -> Option<Transform2D<f32>> {
+        if self.has_perspective() {
+            return None;
+        }
+        let v = &self.matrix.values;
+        Some(Transform2D::row_major(v[0], v[3], v[1], v[4], v[2], v[5]))
+    }
+
+    pub fn inverse(&self) -> Option<Matrix> {
+        let mut result = Matrix::identity();
+        if unsafe { skia::SkiaSkMatrixInvert(&self.matrix, &mut result.matrix) } {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self * other`, which applies `other` first.
+    pub fn concat(&self, other: &Matrix) -> Matrix {
+        let mut result = Matrix::identity();
+        unsafe {
+            skia::SkiaSkMatrixConcat(&self.matrix, &other.matrix, &mut result.matrix);
+        }
+        result
+    }
+
+    /// Maps a point, dividing by w when the matrix has perspective.
+    pub fn map_point(&self, point: &Point2D<f32>) -> Point2D<f32> {
+        let mut point = skia::SkiaPoint::from(*point);
+        unsafe {
+            skia::SkiaSkMatrixMapPoints(&self.matrix, &mut point, 1);
+        }
+        point.into()
+    }
+}
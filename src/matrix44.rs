@@ -0,0 +1,111 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use matrix::Matrix;
+use skia;
+
+use euclid::default::Transform3D;
+
+/// A 4x4 `SkMatrix44`, for 3D transforms such as CSS `transform` with
+/// perspective.
+///
+/// Skia maps column vectors, so a `Matrix44` is the transpose of the
+/// `Transform3D` that euclid, which maps row vectors, uses for the same
+/// transform. The `From` conversions take care of this.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix44 {
+    matrix: skia::SkiaMatrix44,
+}
+
+impl Default for Matrix44 {
+    fn default() -> Matrix44 {
+        Matrix44::identity()
+    }
+}
+
+impl From<Transform3D<f32>> for Matrix44 {
+    fn from(transform: Transform3D<f32>) -> Matrix44 {
+        Matrix44::from_col_major(transform.to_row_major_array())
+    }
+}
+
+impl From<Matrix44> for Transform3D<f32> {
+    fn from(matrix: Matrix44) -> Transform3D<f32> {
+        Transform3D::from_array(matrix.matrix.values)
+    }
+}
+
+impl From<Matrix> for Matrix44 {
+    fn from(matrix: Matrix) -> Matrix44 {
+        let mut result = Matrix44::identity();
+        unsafe {
+            skia::SkiaSkMatrix44FromSkMatrix(matrix.as_raw(), &mut result.matrix);
+        }
+        result
+    }
+}
+
+impl Matrix44 {
+    pub fn identity() -> Matrix44 {
+        Matrix44::from_col_major([1.0, 0.0, 0.0, 0.0,
+                                  0.0, 1.0, 0.0, 0.0,
+                                  0.0, 0.0, 1.0, 0.0,
+                                  0.0, 0.0, 0.0, 1.0])
+    }
+
+    pub fn from_col_major(values: [f32; 16]) -> Matrix44 {
+        Matrix44 {
+            matrix: skia::SkiaMatrix44 { values },
+        }
+    }
+
+    pub fn to_col_major(&self) -> [f32; 16] {
+        self.matrix.values
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> f32 {
+        self.matrix.values[col * 4 + row]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: f32) {
+        self.matrix.values[col * 4 + row] = value;
+    }
+
+    pub fn inverse(&self) -> Option<Matrix44> {
+        let mut result = Matrix44::identity();
+        if unsafe { skia::SkiaSkMatrix44Invert(&self.matrix, &mut result.matrix) } {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self * other`, which applies `other` first.
+    pub fn concat(&self, other: &Matrix44) -> Matrix44 {
+        let mut result = Matrix44::identity();
+        unsafe {
+            skia::SkiaSkMatrix44Concat(&self.matrix, &other.matrix, &mut result.matrix);
+        }
+        result
+    }
+
+    /// Maps the homogeneous column vector `[x, y, z, w]`.
+    pub fn map(&self, vector: [f32; 4]) -> [f32; 4] {
+        let mut result = [0.0; 4];
+        unsafe {
+            skia::SkiaSkMatrix44MapScalars(&self.matrix, vector.as_ptr(), result.as_mut_ptr());
+        }
+        result
+    }
+
+    /// Flattens the transform onto the z = 0 plane by dropping its third row
+    /// and column, keeping any perspective, for drawing with a `Canvas`.
+    pub fn to_matrix(&self) -> Matrix {
+        let mut result = skia::SkiaMatrix { values: [0.0; 9] };
+        unsafe {
+            skia::SkiaSkMatrix44ToSkMatrix(&self.matrix, &mut result);
+        }
+        Matrix::from_values(result.values)
+    }
+}
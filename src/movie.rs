@@ -0,0 +1,182 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use image::Image;
+use skia;
+
+use euclid::default::Size2D;
+use libc::{c_void, size_t};
+use std::time::Duration;
+
+/// How many times an animation repeats, from its NETSCAPE2.0 application
+/// extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoopCount {
+    /// The animation plays once and stops on its last frame.
+    None,
+    /// The animation repeats this many times after it first plays.
+    Finite(u16),
+    Infinite,
+}
+
+/// An animation decoded by `SkMovie`, which can be sampled at any time
+/// between zero and its duration.
+///
+/// Only GIF is supported, and only when the `gif` crate feature is on.
+pub struct Movie {
+    movie: skia::SkiaSkMovieRef,
+    loop_count: LoopCount,
+}
+
+impl Drop for Movie {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkMovieRelease(self.movie);
+        }
+    }
+}
+
+// SkMovie keeps its own copy of the decoded frames; `set_time()` and
+// `frame()` take `&mut self` since they update its current frame.
+unsafe impl Send for Movie {}
+
+impl Movie {
+    /// Decodes a complete animated image. Returns `None` if the data is not
+    /// in a supported format.
+    pub fn from_encoded(data: &[u8]) -> Option<Movie> {
+        let movie = unsafe {
+            skia::SkiaSkMovieCreateFromEncoded(data.as_ptr() as *const c_void,
+                                               data.len() as size_t)
+        };
+        if movie.is_null() {
+            return None;
+        }
+        Some(Movie {
+            movie,
+            loop_count: gif_loop_count(data),
+        })
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkMovieRef {
+        self.movie
+    }
+
+    /// The length of a single play through the animation.
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(unsafe { skia::SkiaSkMovieGetDuration(self.movie) } as u64)
+    }
+
+    pub fn size(&self) -> Size2D<i32> {
+        unsafe {
+            Size2D::new(skia::SkiaSkMovieGetWidth(self.movie),
+                        skia::SkiaSkMovieGetHeight(self.movie))
+        }
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        unsafe {
+            skia::SkiaSkMovieIsOpaque(self.movie)
+        }
+    }
+
+    pub fn loop_count(&self) -> LoopCount {
+        self.loop_count
+    }
+
+    /// Whether the animation repeats at all.
+    pub fn loops(&self) -> bool {
+        self.loop_count != LoopCount::None
+    }
+
+    /// Seeks to `time`, which is pinned to the duration. Returns true if the
+    /// frame changed and so needs to be redrawn.
+    pub fn set_time(&mut self, time: Duration) -> bool {
+        let millis = time.as_millis().min(u32::MAX as u128) as u32;
+        unsafe {
+            skia::SkiaSkMovieSetTime(self.movie, millis)
+        }
+    }
+
+    /// A copy of the frame at the current time, or of the first frame before
+    /// `set_time()` is called.
+    pub fn frame(&mut self) -> Option<Image> {
+        let image = unsafe { skia::SkiaSkMovieGetFrame(self.movie) };
+        if image.is_null() {
+            return None;
+        }
+        Some(unsafe { Image::from_raw(image) })
+    }
+}
+
+/// Reads the loop count from a GIF's NETSCAPE2.0 (or ANIMEXTS1.0) extension.
+/// `SkMovie` ignores application extensions, so the blocks are walked here.
+fn gif_loop_count(data: &[u8]) -> LoopCount {
+    const EXTENSION: u8 = 0x21;
+    const IMAGE: u8 = 0x2c;
+    const APPLICATION: u8 = 0xff;
+
+    // Skips a run of data sub-blocks, returning the offset past the
+    // terminating empty block.
+    fn skip_sub_blocks(data: &[u8], mut offset: usize) -> Option<usize> {
+        loop {
+            let size = *data.get(offset)? as usize;
+            offset += 1 + size;
+            if size == 0 {
+                return Some(offset);
+            }
+        }
+    }
+
+    fn color_table_size(flags: u8) -> usize {
+        if flags & 0x80 != 0 {
+            3 << ((flags & 0x07) + 1)
+        } else {
+            0
+        }
+    }
+
+    // The header and logical screen descriptor, then the global color table.
+    let mut offset = match data.get(10) {
+        Some(&flags) => 13 + color_table_size(flags),
+        None => return LoopCount::None,
+    };
+    loop {
+        match data.get(offset) {
+            Some(&EXTENSION) => {
+                let label = data.get(offset + 1);
+                offset += 2;
+                if label == Some(&APPLICATION) {
+                    let identifier = data.get(offset + 1..offset + 12);
+                    if identifier == Some(b"NETSCAPE2.0") || identifier == Some(b"ANIMEXTS1.0") {
+                        // A three byte sub-block: an id of 1 and a
+                        // little-endian count, where zero repeats forever.
+                        if let Some(&[3, 1, low, high]) = data.get(offset + 12..offset + 16) {
+                            return match u16::from(low) | u16::from(high) << 8 {
+                                0 => LoopCount::Infinite,
+                                count => LoopCount::Finite(count),
+                            };
+                        }
+                    }
+                }
+                offset = match skip_sub_blocks(data, offset) {
+                    Some(offset) => offset,
+                    None => return LoopCount::None,
+                };
+            }
+            Some(&IMAGE) => {
+                let flags = match data.get(offset + 9) {
+                    Some(&flags) => flags,
+                    None => return LoopCount::None,
+                };
+                // The descriptor, local color table and LZW code size.
+                offset += 10 + color_table_size(flags) + 1;
+                offset = match skip_sub_blocks(data, offset) {
+                    Some(offset) => offset,
+                    None => return LoopCount::None,
+                };
+            }
+            _ => return LoopCount::None,
+        }
+    }
+}
@@ -0,0 +1,204 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use blend_mode::{BlendMode, Xfermode};
+use color::Color;
+use color_filter::ColorFilter;
+use draw_looper::DrawLooper;
+use image_filter::ImageFilter;
+use mask_filter::MaskFilter;
+use shader::Shader;
+use skia;
+use stroke::StrokeStyle;
+
+use std::ptr;
+
+/// Whether geometry is filled, stroked or both, matching `SkPaint::Style`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaintStyle {
+    Fill = 0,
+    Stroke = 1,
+    StrokeAndFill = 2,
+}
+
+/// How images are sampled when they are scaled or transformed, matching
+/// `SkPaint::FilterLevel`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterLevel {
+    /// Nearest neighbour.
+    None = 0,
+    /// Bilinear.
+    Low = 1,
+    /// Bilinear with mipmaps when downscaling.
+    Medium = 2,
+    /// Bicubic, or `SkBitmapScaler` when downscaling.
+    High = 3,
+}
+
+/// How geometry is drawn: its color, style and the effects applied to it.
+pub struct Paint {
+    paint: skia::SkiaSkPaintRef,
+}
+
+impl Drop for Paint {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkPaintDelete(self.paint);
+        }
+    }
+}
+
+impl Clone for Paint {
+    fn clone(&self) -> Paint {
+        Paint {
+            paint: unsafe { skia::SkiaSkPaintClone(self.paint) },
+        }
+    }
+}
+
+unsafe impl Send for Paint {}
+
+impl Default for Paint {
+    fn default() -> Paint {
+        Paint::new()
+    }
+}
+
+impl Paint {
+    /// An opaque black, aliased fill.
+    pub fn new() -> Paint {
+        Paint {
+            paint: unsafe { skia::SkiaSkPaintCreate() },
+        }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkPaintRef {
+        self.paint
+    }
+
+    pub fn color(&self) -> Color {
+        Color(unsafe { skia::SkiaSkPaintGetColor(self.paint) })
+    }
+
+    pub fn set_color(&mut self, color: Color) {
+        unsafe {
+            skia::SkiaSkPaintSetColor(self.paint, color.0);
+        }
+    }
+
+    pub fn is_anti_alias(&self) -> bool {
+        unsafe {
+            skia::SkiaSkPaintIsAntiAlias(self.paint)
+        }
+    }
+
+    pub fn set_anti_alias(&mut self, anti_alias: bool) {
+        unsafe {
+            skia::SkiaSkPaintSetAntiAlias(self.paint, anti_alias);
+        }
+    }
+
+    pub fn style(&self) -> PaintStyle {
+        match unsafe { skia::SkiaSkPaintGetStyle(self.paint) } {
+            1 => PaintStyle::Stroke,
+            2 => PaintStyle::StrokeAndFill,
+            _ => PaintStyle::Fill,
+        }
+    }
+
+    pub fn set_style(&mut self, style: PaintStyle) {
+        unsafe {
+            skia::SkiaSkPaintSetStyle(self.paint, style as i32);
+        }
+    }
+
+    /// Sets the stroke parameters and path effect. These only take effect
+    /// when the style strokes.
+    pub fn set_stroke(&mut self, stroke: &StrokeStyle) {
+        unsafe {
+            skia::SkiaSkPaintSetStroke(self.paint,
+                                       stroke.width,
+                                       stroke.cap as i32,
+                                       stroke.join as i32,
+                                       stroke.miter_limit,
+                                       stroke.path_effect_as_raw());
+        }
+    }
+
+    pub fn filter_level(&self) -> FilterLevel {
+        match unsafe { skia::SkiaSkPaintGetFilterLevel(self.paint) } {
+            1 => FilterLevel::Low,
+            2 => FilterLevel::Medium,
+            3 => FilterLevel::High,
+            _ => FilterLevel::None,
+        }
+    }
+
+    pub fn set_filter_level(&mut self, level: FilterLevel) {
+        unsafe {
+            skia::SkiaSkPaintSetFilterLevel(self.paint, level as i32);
+        }
+    }
+
+    /// Fills with `shader` instead of the paint's colour, whose alpha still
+    /// applies. `None` goes back to the plain colour.
+    pub fn set_shader(&mut self, shader: Option<&Shader>) {
+        unsafe {
+            skia::SkiaSkPaintSetShader(self.paint, shader.map_or(ptr::null_mut(), Shader::as_raw));
+        }
+    }
+
+    /// Filters everything drawn with this paint through `image_filter`, or
+    /// nothing if it is `None`. On the paint of `Canvas::save_layer`, the
+    /// filter applies to the whole layer when it is restored.
+    pub fn set_image_filter(&mut self, image_filter: Option<&ImageFilter>) {
+        unsafe {
+            skia::SkiaSkPaintSetImageFilter(self.paint,
+                                            image_filter.map_or(ptr::null_mut(), ImageFilter::as_raw));
+        }
+    }
+
+    /// Passes every colour drawn with this paint through `color_filter`, or
+    /// none if it is `None`.
+    pub fn set_color_filter(&mut self, color_filter: Option<&ColorFilter>) {
+        unsafe {
+            skia::SkiaSkPaintSetColorFilter(self.paint,
+                                            color_filter.map_or(ptr::null_mut(), ColorFilter::as_raw));
+        }
+    }
+
+    /// Alters the coverage of everything drawn with this paint through
+    /// `mask_filter`, or nothing if it is `None`.
+    pub fn set_mask_filter(&mut self, mask_filter: Option<&MaskFilter>) {
+        unsafe {
+            skia::SkiaSkPaintSetMaskFilter(self.paint,
+                                           mask_filter.map_or(ptr::null_mut(), MaskFilter::as_raw));
+        }
+    }
+    /// Combines what this paint draws with the destination using `mode`.
+    pub fn set_blend_mode(&mut self, mode: BlendMode) {
+        unsafe {
+            skia::SkiaSkPaintSetXfermodeMode(self.paint, mode as i32);
+        }
+    }
+
+    /// Combines what this paint draws with the destination using
+    /// `xfermode`, or `SrcOver` if it is `None`.
+    pub fn set_xfermode(&mut self, xfermode: Option<&Xfermode>) {
+        unsafe {
+            skia::SkiaSkPaintSetXfermode(self.paint,
+                                         xfermode.map_or(ptr::null_mut(), Xfermode::as_raw));
+        }
+    }
+    /// Draws everything drawn with this paint in the passes of `looper`, or
+    /// once if it is `None`.
+    pub fn set_looper(&mut self, looper: Option<&DrawLooper>) {
+        unsafe {
+            skia::SkiaSkPaintSetLooper(self.paint,
+                                       looper.map_or(ptr::null_mut(), DrawLooper::as_raw));
+        }
+    }
+}
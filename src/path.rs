@@ -0,0 +1,312 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use rrect::RRect;
+use skia;
+use stroke::StrokeStyle;
+
+use euclid::default::{Point2D, Rect, Transform2D};
+
+/// How the interior of a path is computed, matching `SkPath::FillType`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillType {
+    Winding = 0,
+    EvenOdd = 1,
+    InverseWinding = 2,
+    InverseEvenOdd = 3,
+}
+
+/// A boolean operation between two paths, matching `SkPathOp`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathOp {
+    /// Subtract the second path from the first.
+    Difference = 0,
+    Intersect = 1,
+    Union = 2,
+    Xor = 3,
+    /// Subtract the first path from the second.
+    ReverseDifference = 4,
+}
+
+pub struct Path {
+    path: skia::SkiaSkPathRef,
+}
+
+impl Drop for Path {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkPathDelete(self.path);
+        }
+    }
+}
+
+impl Clone for Path {
+    fn clone(&self) -> Path {
+        unsafe {
+            Path::from_raw(skia::SkiaSkPathClone(self.path))
+        }
+    }
+}
+
+// SkPath shares its point storage through an atomically reference counted
+// SkPathRef and copies it on write, so a path may move between threads.
+unsafe impl Send for Path {}
+
+impl Default for Path {
+    fn default() -> Path {
+        Path::new()
+    }
+}
+
+impl Path {
+    pub fn new() -> Path {
+        unsafe {
+            Path::from_raw(skia::SkiaSkPathCreate())
+        }
+    }
+
+    /// Takes ownership of a path allocated by the Skia C API.
+    ///
+    /// # Safety
+    ///
+    /// `path` must be a valid `SkPath` that nothing else will delete.
+    pub unsafe fn from_raw(path: skia::SkiaSkPathRef) -> Path {
+        Path { path }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkPathRef {
+        self.path
+    }
+
+    pub fn reset(&mut self) {
+        unsafe {
+            skia::SkiaSkPathReset(self.path);
+        }
+    }
+
+    pub fn fill_type(&self) -> FillType {
+        match unsafe { skia::SkiaSkPathGetFillType(self.path) } {
+            1 => FillType::EvenOdd,
+            2 => FillType::InverseWinding,
+            3 => FillType::InverseEvenOdd,
+            _ => FillType::Winding,
+        }
+    }
+
+    pub fn set_fill_type(&mut self, fill_type: FillType) {
+        unsafe {
+            skia::SkiaSkPathSetFillType(self.path, fill_type as i32);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            skia::SkiaSkPathIsEmpty(self.path)
+        }
+    }
+
+    /// The bounds of all of the path's points, including control points.
+    pub fn bounds(&self) -> Rect<f32> {
+        let mut bounds = skia::SkiaRect::default();
+        unsafe {
+            skia::SkiaSkPathGetBounds(self.path, &mut bounds);
+        }
+        bounds.into()
+    }
+
+    /// The smallest rectangle that encloses the path's curves, ignoring
+    /// control points that lie outside of them. Returns `None` if the path
+    /// has non-finite coordinates.
+    pub fn tight_bounds(&self) -> Option<Rect<f32>> {
+        let mut bounds = skia::SkiaRect::default();
+        if unsafe { skia::SkiaSkPathTightBounds(self.path, &mut bounds) } {
+            Some(bounds.into())
+        } else {
+            None
+        }
+    }
+
+    /// Whether the point is inside the path, according to its fill type.
+    pub fn contains(&self, point: &Point2D<f32>) -> bool {
+        unsafe {
+            skia::SkiaSkPathContains(self.path, point.x, point.y)
+        }
+    }
+
+    pub fn move_to(&mut self, point: &Point2D<f32>) {
+        unsafe {
+            skia::SkiaSkPathMoveTo(self.path, point.x, point.y);
+        }
+    }
+
+    pub fn line_to(&mut self, point: &Point2D<f32>) {
+        unsafe {
+            skia::SkiaSkPathLineTo(self.path, point.x, point.y);
+        }
+    }
+
+    pub fn quad_to(&mut self, control: &Point2D<f32>, point: &Point2D<f32>) {
+        unsafe {
+            skia::SkiaSkPathQuadTo(self.path, control.x, control.y, point.x, point.y);
+        }
+    }
+
+    pub fn conic_to(&mut self, control: &Point2D<f32>, point: &Point2D<f32>, weight: f32) {
+        unsafe {
+            skia::SkiaSkPathConicTo(self.path, control.x, control.y, point.x, point.y, weight);
+        }
+    }
+
+    pub fn cubic_to(&mut self,
+                    control1: &Point2D<f32>,
+                    control2: &Point2D<f32>,
+                    point: &Point2D<f32>) {
+        unsafe {
+            skia::SkiaSkPathCubicTo(self.path,
+                                    control1.x, control1.y,
+                                    control2.x, control2.y,
+                                    point.x, point.y);
+        }
+    }
+
+    pub fn close(&mut self) {
+        unsafe {
+            skia::SkiaSkPathClose(self.path);
+        }
+    }
+
+    pub fn add_rect(&mut self, rect: &Rect<f32>, clockwise: bool) {
+        let rect = skia::SkiaRect::from(*rect);
+        unsafe {
+            skia::SkiaSkPathAddRect(self.path, &rect, clockwise);
+        }
+    }
+
+    pub fn add_oval(&mut self, oval: &Rect<f32>, clockwise: bool) {
+        let oval = skia::SkiaRect::from(*oval);
+        unsafe {
+            skia::SkiaSkPathAddOval(self.path, &oval, clockwise);
+        }
+    }
+
+    pub fn add_rrect(&mut self, rrect: &RRect, clockwise: bool) {
+        unsafe {
+            skia::SkiaSkPathAddRRect(self.path, rrect.as_raw(), clockwise);
+        }
+    }
+
+    pub fn add_circle(&mut self, center: &Point2D<f32>, radius: f32, clockwise: bool) {
+        unsafe {
+            skia::SkiaSkPathAddCircle(self.path, center.x, center.y, radius, clockwise);
+        }
+    }
+
+    pub fn add_path(&mut self, other: &Path) {
+        unsafe {
+            skia::SkiaSkPathAddPath(self.path, other.path);
+        }
+    }
+
+    pub fn transform(&mut self, transform: &Transform2D<f32>) {
+        let matrix = skia::SkiaMatrix::from(*transform);
+        unsafe {
+            skia::SkiaSkPathTransform(self.path, &matrix);
+        }
+    }
+
+    /// Combines this path with `other`, producing a path made of
+    /// non-overlapping contours. Returns `None` if SkPathOps could not
+    /// compute a result.
+    pub fn op(&self, other: &Path, op: PathOp) -> Option<Path> {
+        let result = Path::new();
+        if unsafe { skia::SkiaSkPathOp(self.path, other.path, op as i32, result.path) } {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a path with the same filled area as this one, but without
+    /// self-intersecting or overlapping contours.
+    pub fn simplify(&self) -> Option<Path> {
+        let result = Path::new();
+        if unsafe { skia::SkiaSkPathSimplify(self.path, result.path) } {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the outline of this path stroked with `style`, as a path to
+    /// be filled with the winding rule. Returns `None` for hairlines.
+    pub fn stroke(&self, style: &StrokeStyle) -> Option<Path> {
+        let result = Path::new();
+        if unsafe {
+            skia::SkiaSkPathStroke(self.path,
+                                   style.width,
+                                   style.cap as i32,
+                                   style.join as i32,
+                                   style.miter_limit,
+                                   style.path_effect_as_raw(),
+                                   result.path)
+        } {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the point lies on this path when stroked with `style`.
+    pub fn stroke_contains(&self, style: &StrokeStyle, point: &Point2D<f32>) -> bool {
+        unsafe {
+            skia::SkiaSkPathStrokeContains(self.path,
+                                           style.width,
+                                           style.cap as i32,
+                                           style.join as i32,
+                                           style.miter_limit,
+                                           style.path_effect_as_raw(),
+                                           point.x,
+                                           point.y)
+        }
+    }
+}
+
+/// Folds any number of paths together with boolean operations.
+///
+/// The builder starts out empty, so the first path added is combined with an
+/// empty path; adding it with `PathOp::Union` uses it as-is.
+pub struct PathOpBuilder {
+    operands: Vec<(Path, PathOp)>,
+}
+
+impl Default for PathOpBuilder {
+    fn default() -> PathOpBuilder {
+        PathOpBuilder::new()
+    }
+}
+
+impl PathOpBuilder {
+    pub fn new() -> PathOpBuilder {
+        PathOpBuilder {
+            operands: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, path: Path, op: PathOp) -> &mut PathOpBuilder {
+        self.operands.push((path, op));
+        self
+    }
+
+    /// Applies every operation in the order the paths were added. Returns
+    /// `None` if any of them fails.
+    pub fn resolve(&self) -> Option<Path> {
+        let mut result = Path::new();
+        for &(ref path, op) in &self.operands {
+            result = result.op(path, op)?;
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,139 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use matrix::Matrix;
+use path::Path;
+use skia;
+
+/// How `PathEffect::path_1d` places each copy of its stamp along the path,
+/// matching `SkPath1DPathEffect::Style`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Path1DStyle {
+    /// Moves the stamp to each position without turning it.
+    Translate = 0,
+    /// Turns the stamp to follow the tangent of the path.
+    Rotate = 1,
+    /// Bends every point of the stamp along the path, turning its lines into
+    /// curves.
+    Morph = 2,
+}
+
+/// A reference counted `SkPathEffect`, which alters the geometry of a path
+/// before it is stroked or filled.
+pub struct PathEffect {
+    path_effect: skia::SkiaSkPathEffectRef,
+}
+
+impl Drop for PathEffect {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkPathEffectRelease(self.path_effect);
+        }
+    }
+}
+
+impl Clone for PathEffect {
+    fn clone(&self) -> PathEffect {
+        unsafe {
+            skia::SkiaSkPathEffectRetain(self.path_effect);
+        }
+        PathEffect {
+            path_effect: self.path_effect,
+        }
+    }
+}
+
+// Path effects are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for PathEffect {}
+unsafe impl Sync for PathEffect {}
+
+impl PathEffect {
+    fn from_raw(path_effect: skia::SkiaSkPathEffectRef) -> Option<PathEffect> {
+        if path_effect.is_null() {
+            return None;
+        }
+        Some(PathEffect { path_effect })
+    }
+
+    /// Breaks the path into dashes. `intervals` alternates the lengths of the
+    /// "on" and "off" spans and must have an even number of non-negative
+    /// entries with a positive sum; `phase` is the offset into the pattern.
+    pub fn dash(intervals: &[f32], phase: f32) -> Option<PathEffect> {
+        PathEffect::from_raw(unsafe {
+            skia::SkiaSkPathEffectCreateDash(intervals.as_ptr(), intervals.len() as i32, phase)
+        })
+    }
+
+    /// Rounds every sharp corner with an arc of up to `radius`, which must be
+    /// positive.
+    pub fn corner(radius: f32) -> Option<PathEffect> {
+        PathEffect::from_raw(unsafe { skia::SkiaSkPathEffectCreateCorner(radius) })
+    }
+
+    /// Chops the path into segments of about `segment_length` and moves
+    /// their ends randomly by up to `deviation`. The same `seed` always
+    /// gives the same jitter.
+    pub fn discrete(segment_length: f32, deviation: f32, seed: u32) -> Option<PathEffect> {
+        PathEffect::from_raw(unsafe {
+            skia::SkiaSkPathEffectCreateDiscrete(segment_length, deviation, seed)
+        })
+    }
+
+    /// Replaces the path with copies of `stamp` every `advance` units along
+    /// it, starting `phase` units in. The result is filled.
+    pub fn path_1d(stamp: &Path, advance: f32, phase: f32, style: Path1DStyle)
+                   -> Option<PathEffect> {
+        PathEffect::from_raw(unsafe {
+            skia::SkiaSkPathEffectCreatePath1D(stamp.as_raw(), advance, phase, style as i32)
+        })
+    }
+
+    /// Fills the path with horizontal lines `width` wide, one per row of the
+    /// lattice given by `matrix`. The matrix must be invertible and affine.
+    pub fn line_2d(width: f32, matrix: &Matrix) -> Option<PathEffect> {
+        PathEffect::from_raw(unsafe { skia::SkiaSkPathEffectCreateLine2D(width, matrix.as_raw()) })
+    }
+
+    /// Fills the path with a copy of `stamp` at each point of the lattice
+    /// given by `matrix`. The matrix must be invertible and affine.
+    pub fn path_2d(matrix: &Matrix, stamp: &Path) -> Option<PathEffect> {
+        PathEffect::from_raw(unsafe {
+            skia::SkiaSkPathEffectCreatePath2D(matrix.as_raw(), stamp.as_raw())
+        })
+    }
+
+    /// Applies `inner`, then `outer` to its result.
+    pub fn compose(outer: &PathEffect, inner: &PathEffect) -> PathEffect {
+        PathEffect::from_raw(unsafe {
+            skia::SkiaSkPathEffectCreateCompose(outer.path_effect, inner.path_effect)
+        }).expect("SkComposePathEffect::Create failed")
+    }
+
+    /// Applies both effects to the original path and draws both results.
+    pub fn sum(first: &PathEffect, second: &PathEffect) -> PathEffect {
+        PathEffect::from_raw(unsafe {
+            skia::SkiaSkPathEffectCreateSum(first.path_effect, second.path_effect)
+        }).expect("SkSumPathEffect::Create failed")
+    }
+
+    /// Returns the geometry this effect turns `path` into, treating `path` as
+    /// a hairline. Effects that widen their output, like `line_2d`, return
+    /// the outline to be filled. Returns `None` if the effect does not
+    /// apply, such as a dash on an empty path.
+    pub fn apply(&self, path: &Path) -> Option<Path> {
+        let result = Path::new();
+        if unsafe {
+            skia::SkiaSkPathEffectFilterPath(self.path_effect, path.as_raw(), result.as_raw())
+        } {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkPathEffectRef {
+        self.path_effect
+    }
+}
@@ -0,0 +1,109 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use path::Path;
+use skia;
+
+use euclid::default::{Point2D, Vector2D};
+
+/// Walks the contours of a path, yielding a `ContourMeasure` for each one.
+///
+/// As with `SkPathMeasure::nextContour()`, iteration stops at the first
+/// contour that has no length.
+pub struct PathMeasure {
+    measure: skia::SkiaSkPathMeasureRef,
+}
+
+impl Drop for PathMeasure {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkPathMeasureDelete(self.measure);
+        }
+    }
+}
+
+unsafe impl Send for PathMeasure {}
+
+impl PathMeasure {
+    /// Measures a copy of `path`. If `force_closed` is true, every contour is
+    /// measured as if it were closed. Conics are measured as the quads that
+    /// approximate them to within a quarter of a unit.
+    pub fn new(path: &Path, force_closed: bool) -> PathMeasure {
+        PathMeasure {
+            measure: unsafe { skia::SkiaSkPathMeasureCreate(path.as_raw(), force_closed) },
+        }
+    }
+}
+
+impl Iterator for PathMeasure {
+    type Item = ContourMeasure;
+
+    fn next(&mut self) -> Option<ContourMeasure> {
+        let contour = unsafe { skia::SkiaSkPathMeasureNextContour(self.measure) };
+        if contour.is_null() {
+            return None;
+        }
+        Some(ContourMeasure { contour })
+    }
+}
+
+/// The length of a single contour, and positions and sub-paths along it.
+pub struct ContourMeasure {
+    contour: skia::SkiaSkContourMeasureRef,
+}
+
+impl Drop for ContourMeasure {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkContourMeasureDelete(self.contour);
+        }
+    }
+}
+
+unsafe impl Send for ContourMeasure {}
+
+impl ContourMeasure {
+    pub fn length(&self) -> f32 {
+        unsafe {
+            skia::SkiaSkContourMeasureGetLength(self.contour)
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        unsafe {
+            skia::SkiaSkContourMeasureIsClosed(self.contour)
+        }
+    }
+
+    /// The point at `distance` along the contour and the unit tangent there.
+    /// Distances outside of the contour are pinned to its ends.
+    pub fn pos_tan(&self, distance: f32) -> Option<(Point2D<f32>, Vector2D<f32>)> {
+        let mut position = skia::SkiaPoint::default();
+        let mut tangent = skia::SkiaPoint::default();
+        if unsafe {
+            skia::SkiaSkContourMeasureGetPosTan(self.contour, distance, &mut position, &mut tangent)
+        } {
+            Some((position.into(), Vector2D::new(tangent.x, tangent.y)))
+        } else {
+            None
+        }
+    }
+
+    /// The part of the contour between `start` and `stop`. Returns `None` if
+    /// that interval is empty once pinned to the contour.
+    pub fn segment(&self, start: f32, stop: f32, start_with_move_to: bool) -> Option<Path> {
+        let result = Path::new();
+        if unsafe {
+            skia::SkiaSkContourMeasureGetSegment(self.contour,
+                                                 start,
+                                                 stop,
+                                                 result.as_raw(),
+                                                 start_with_move_to)
+        } {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
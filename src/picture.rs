@@ -0,0 +1,107 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use canvas::Canvas;
+use skia;
+
+use euclid::default::Size2D;
+
+/// A reference counted `SkPicture`: a recording of drawing commands that can
+/// be played back onto any canvas or tiled by `Shader::picture`.
+pub struct Picture {
+    picture: skia::SkiaSkPictureRef,
+}
+
+impl Drop for Picture {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkPictureRelease(self.picture);
+        }
+    }
+}
+
+impl Clone for Picture {
+    fn clone(&self) -> Picture {
+        unsafe {
+            skia::SkiaSkPictureRetain(self.picture);
+        }
+        Picture {
+            picture: self.picture,
+        }
+    }
+}
+
+// Pictures are immutable once recorded and SkRefCnt is thread-safe.
+unsafe impl Send for Picture {}
+unsafe impl Sync for Picture {}
+
+impl Picture {
+    /// The size given to `PictureRecorder::begin_recording`.
+    pub fn size(&self) -> Size2D<i32> {
+        unsafe {
+            Size2D::new(skia::SkiaSkPictureGetWidth(self.picture),
+                        skia::SkiaSkPictureGetHeight(self.picture))
+        }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkPictureRef {
+        self.picture
+    }
+}
+
+/// Records the drawing done to its canvas into a `Picture`.
+pub struct PictureRecorder {
+    recorder: skia::SkiaSkPictureRecorderRef,
+}
+
+impl Drop for PictureRecorder {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkPictureRecorderDelete(self.recorder);
+        }
+    }
+}
+
+// The recorder is uniquely owned and only touched through &mut self, and its
+// canvas borrows it, so it cannot move while a recording is being drawn.
+unsafe impl Send for PictureRecorder {}
+
+impl Default for PictureRecorder {
+    fn default() -> PictureRecorder {
+        PictureRecorder::new()
+    }
+}
+
+impl PictureRecorder {
+    pub fn new() -> PictureRecorder {
+        PictureRecorder {
+            recorder: unsafe { skia::SkiaSkPictureRecorderCreate() },
+        }
+    }
+
+    /// Starts a recording of `size`, discarding any unfinished one, and
+    /// returns the canvas to draw it with. Returns `None` if the size is
+    /// empty.
+    pub fn begin_recording(&mut self, size: Size2D<i32>) -> Option<Canvas<'_>> {
+        unsafe {
+            let canvas = skia::SkiaSkPictureRecorderBeginRecording(self.recorder,
+                                                                   size.width,
+                                                                   size.height);
+            if canvas.is_null() {
+                None
+            } else {
+                Some(Canvas::from_raw(canvas))
+            }
+        }
+    }
+
+    /// Ends the recording and returns it, or `None` if none was started.
+    pub fn finish_recording(&mut self) -> Option<Picture> {
+        let picture = unsafe { skia::SkiaSkPictureRecorderEndRecording(self.recorder) };
+        if picture.is_null() {
+            return None;
+        }
+        Some(Picture { picture })
+    }
+}
@@ -0,0 +1,132 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use color::Color;
+use image_info::{AlphaType, ColorType, ImageInfo};
+use skia;
+
+use libc::{c_void, size_t};
+
+/// Pixels described by an `ImageInfo`, borrowed from the caller.
+#[derive(Clone, Copy, Debug)]
+pub struct Pixmap<'a> {
+    info: ImageInfo,
+    pixels: &'a [u8],
+    row_bytes: usize,
+}
+
+impl<'a> Pixmap<'a> {
+    /// Returns `None` if the rows are shorter than `info.min_row_bytes()` or
+    /// `pixels` is too short to hold them.
+    pub fn new(info: ImageInfo, pixels: &'a [u8], row_bytes: usize) -> Option<Pixmap<'a>> {
+        if row_bytes < info.min_row_bytes() || pixels.len() < info.compute_byte_size(row_bytes) {
+            return None;
+        }
+        Some(Pixmap {
+            info,
+            pixels,
+            row_bytes,
+        })
+    }
+
+    pub fn info(&self) -> &ImageInfo {
+        &self.info
+    }
+
+    pub fn pixels(&self) -> &'a [u8] {
+        self.pixels
+    }
+
+    pub fn row_bytes(&self) -> usize {
+        self.row_bytes
+    }
+
+    /// Converts the pixels into `dst`, which is described by `dst_info` and
+    /// has rows `dst_row_bytes` apart.
+    ///
+    /// Any pair of Alpha8, RGB565, ARGB4444, RGBA8888 and BGRA8888 works,
+    /// swapping red and blue as needed. Only the 32-bit formats can be
+    /// unpremultiplied; ARGB4444 is dithered from premultiplied pixels.
+    /// Index8 pixels need a palette and go through `convert_index8_to`.
+    /// Returns `false`, leaving `dst` unspecified, if the sizes differ, `dst`
+    /// is too short, or the pair is unsupported.
+    pub fn convert_to(&self, dst_info: &ImageInfo, dst: &mut [u8], dst_row_bytes: usize) -> bool {
+        self.convert_pixels(None, dst_info, dst, dst_row_bytes)
+    }
+
+    /// Like `convert_to`, for Index8 pixels whose indices look up the
+    /// unpremultiplied colours in `palette`. Indices past its end give
+    /// transparent black. Returns `false` if the pixels are not Index8 or
+    /// the palette is empty or has more than 256 colours.
+    pub fn convert_index8_to(&self,
+                             palette: &[Color],
+                             dst_info: &ImageInfo,
+                             dst: &mut [u8],
+                             dst_row_bytes: usize)
+                             -> bool {
+        self.info.color_type == ColorType::Index8 &&
+            self.convert_pixels(Some(palette), dst_info, dst, dst_row_bytes)
+    }
+
+    fn convert_pixels(&self,
+                      palette: Option<&[Color]>,
+                      dst_info: &ImageInfo,
+                      dst: &mut [u8],
+                      dst_row_bytes: usize)
+                      -> bool {
+        if dst_row_bytes < dst_info.min_row_bytes() ||
+           dst.len() < dst_info.compute_byte_size(dst_row_bytes) {
+            return false;
+        }
+        let palette: Vec<u32> = palette.map_or(Vec::new(), |palette| {
+            palette.iter().map(|color| color.0).collect()
+        });
+        let src_info = skia::SkiaImageInfo::from(self.info);
+        let dst_info = skia::SkiaImageInfo::from(*dst_info);
+        unsafe {
+            skia::SkiaConvertPixels(&src_info,
+                                    self.pixels.as_ptr() as *const c_void,
+                                    self.row_bytes as size_t,
+                                    palette.as_ptr(),
+                                    palette.len() as i32,
+                                    &dst_info,
+                                    dst.as_mut_ptr() as *mut c_void,
+                                    dst_row_bytes as size_t)
+        }
+    }
+
+    /// Converts the pixels into a new buffer with packed rows, as in
+    /// `convert_to`.
+    pub fn convert(&self, color_type: ColorType, alpha_type: AlphaType) -> Option<Vec<u8>> {
+        self.convert_into_vec(None, color_type, alpha_type)
+    }
+
+    /// Converts Index8 pixels into a new buffer with packed rows, as in
+    /// `convert_index8_to`.
+    pub fn convert_index8(&self,
+                          palette: &[Color],
+                          color_type: ColorType,
+                          alpha_type: AlphaType)
+                          -> Option<Vec<u8>> {
+        if self.info.color_type != ColorType::Index8 {
+            return None;
+        }
+        self.convert_into_vec(Some(palette), color_type, alpha_type)
+    }
+
+    fn convert_into_vec(&self,
+                        palette: Option<&[Color]>,
+                        color_type: ColorType,
+                        alpha_type: AlphaType)
+                        -> Option<Vec<u8>> {
+        let dst_info = ImageInfo::new(self.info.size(), color_type, alpha_type);
+        let row_bytes = dst_info.min_row_bytes();
+        let mut result = vec![0; dst_info.compute_byte_size(row_bytes)];
+        if self.convert_pixels(palette, &dst_info, &mut result, row_bytes) {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
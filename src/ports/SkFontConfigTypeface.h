@@ -16,6 +16,7 @@ class FontConfigTypeface : public SkTypeface_FreeType {
     SkFontConfigInterface::FontIdentity fIdentity;
     SkString fFamilyName;
     SkStream* fLocalStream;
+    int fLocalTTCIndex;
 
 public:
     static FontConfigTypeface* Create(Style style,
@@ -24,8 +25,11 @@ public:
         return SkNEW_ARGS(FontConfigTypeface, (style, fi, familyName));
     }
 
-    static FontConfigTypeface* Create(Style style, bool fixedWidth, SkStream* localStream) {
-        return SkNEW_ARGS(FontConfigTypeface, (style, fixedWidth, localStream));
+    static FontConfigTypeface* Create(Style style, bool fixedWidth, SkStream* localStream,
+                                      int ttcIndex = 0,
+                                      const SkString& familyName = SkString()) {
+        return SkNEW_ARGS(FontConfigTypeface,
+                          (style, fixedWidth, localStream, ttcIndex, familyName));
     }
 
     virtual ~FontConfigTypeface() {
@@ -56,11 +60,15 @@ protected:
             : INHERITED(style, SkTypefaceCache::NewFontID(), false)
             , fIdentity(fi)
             , fFamilyName(familyName)
-            , fLocalStream(NULL) {}
+            , fLocalStream(NULL)
+            , fLocalTTCIndex(0) {}
 
-    FontConfigTypeface(Style style, bool fixedWidth, SkStream* localStream)
-            : INHERITED(style, SkTypefaceCache::NewFontID(), fixedWidth) {
-        // we default to empty fFamilyName and fIdentity
+    FontConfigTypeface(Style style, bool fixedWidth, SkStream* localStream,
+                       int ttcIndex = 0, const SkString& familyName = SkString())
+            : INHERITED(style, SkTypefaceCache::NewFontID(), fixedWidth)
+            , fFamilyName(familyName)
+            , fLocalTTCIndex(ttcIndex) {
+        // we default to an empty fIdentity
         fLocalStream = localStream;
         SkSafeRef(localStream);
     }
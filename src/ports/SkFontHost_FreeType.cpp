@@ -1681,9 +1681,15 @@ size_t SkTypeface_FreeType::onGetTableData(SkFontTableTag tag, size_t offset,
 
 /*  Export this so that other parts of our FonttHost port can make use of our
     ability to extract the name+style from a stream, using FreeType's api.
+    ttcIndex picks the face in a TrueType collection; this fails if there is no
+    such face.
 */
-bool find_name_and_attributes(SkStream* stream, SkString* name,
+bool find_name_and_attributes(SkStream* stream, int ttcIndex, SkString* name,
                               SkTypeface::Style* style, bool* isFixedPitch) {
+    if (ttcIndex < 0) {
+        return false;
+    }
+
     FT_Library  library;
     if (FT_Init_FreeType(&library)) {
         return false;
@@ -1711,7 +1717,7 @@ bool find_name_and_attributes(SkStream* stream, SkString* name,
     }
 
     FT_Face face;
-    if (FT_Open_Face(library, &args, 0, &face)) {
+    if (FT_Open_Face(library, &args, ttcIndex, &face)) {
         FT_Done_FreeType(library);
         return false;
     }
@@ -1738,3 +1744,8 @@ bool find_name_and_attributes(SkStream* stream, SkString* name,
     FT_Done_FreeType(library);
     return true;
 }
+
+bool find_name_and_attributes(SkStream* stream, SkString* name,
+                              SkTypeface::Style* style, bool* isFixedPitch) {
+    return find_name_and_attributes(stream, 0, name, style, isFixedPitch);
+}
@@ -169,7 +169,7 @@ SkStream* FontConfigTypeface::onOpenStream(int* ttcIndex) const {
     SkStream* stream = this->getLocalStream();
     if (stream) {
         // should have been provided by CreateFromStream()
-        *ttcIndex = 0;
+        *ttcIndex = fLocalTTCIndex;
 
         SkAutoTUnref<SkStream> dupStream(stream->duplicate());
         if (dupStream) {
@@ -0,0 +1,252 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use path::Path;
+use skia;
+
+use euclid::default::{Point2D, Rect, Vector2D};
+use std::marker::PhantomData;
+
+/// How two regions, or a clip and a shape, are combined, matching
+/// `SkRegion::Op`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegionOp {
+    /// Subtract the operand from the region.
+    Difference = 0,
+    Intersect = 1,
+    Union = 2,
+    Xor = 3,
+    /// Subtract the region from the operand.
+    ReverseDifference = 4,
+    Replace = 5,
+}
+
+/// A set of integer pixel rectangles, stored as sorted runs like `SkRegion`.
+pub struct Region {
+    region: skia::SkiaSkRegionRef,
+}
+
+impl Drop for Region {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkRegionDelete(self.region);
+        }
+    }
+}
+
+impl Clone for Region {
+    fn clone(&self) -> Region {
+        Region {
+            region: unsafe { skia::SkiaSkRegionClone(self.region) },
+        }
+    }
+}
+
+impl PartialEq for Region {
+    fn eq(&self, other: &Region) -> bool {
+        unsafe {
+            skia::SkiaSkRegionEquals(self.region, other.region)
+        }
+    }
+}
+
+// SkRegion shares its runs through an atomically reference counted RunHead
+// and copies them on write.
+unsafe impl Send for Region {}
+
+impl Default for Region {
+    fn default() -> Region {
+        Region::new()
+    }
+}
+
+impl Region {
+    /// An empty region.
+    pub fn new() -> Region {
+        Region {
+            region: unsafe { skia::SkiaSkRegionCreate() },
+        }
+    }
+
+    /// A region that covers `rect`. Accepts either a `Rect<i32>` or a
+    /// `Box2D<i32>`.
+    pub fn from_rect<R>(rect: R) -> Region where R: Into<skia::SkiaIRect> {
+        let mut region = Region::new();
+        region.set_rect(rect);
+        region
+    }
+
+    /// The union of `rects`.
+    pub fn from_rects(rects: &[Rect<i32>]) -> Region {
+        let rects: Vec<skia::SkiaIRect> = rects.iter().map(|rect| (*rect).into()).collect();
+        let region = Region::new();
+        unsafe {
+            skia::SkiaSkRegionSetRects(region.region, rects.as_ptr(), rects.len() as i32);
+        }
+        region
+    }
+
+    /// The pixels covered by `path`, limited to those in `clip`.
+    pub fn from_path(path: &Path, clip: &Region) -> Region {
+        let region = Region::new();
+        unsafe {
+            skia::SkiaSkRegionSetPath(region.region, path.as_raw(), clip.region);
+        }
+        region
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkRegionRef {
+        self.region
+    }
+
+    pub fn set_empty(&mut self) {
+        unsafe {
+            skia::SkiaSkRegionSetEmpty(self.region);
+        }
+    }
+
+    pub fn set_rect<R>(&mut self, rect: R) where R: Into<skia::SkiaIRect> {
+        let rect = rect.into();
+        unsafe {
+            skia::SkiaSkRegionSetRect(self.region, &rect);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            skia::SkiaSkRegionIsEmpty(self.region)
+        }
+    }
+
+    /// Whether the region is a single rectangle.
+    pub fn is_rect(&self) -> bool {
+        unsafe {
+            skia::SkiaSkRegionIsRect(self.region)
+        }
+    }
+
+    pub fn bounds(&self) -> Rect<i32> {
+        let mut bounds = skia::SkiaIRect::default();
+        unsafe {
+            skia::SkiaSkRegionGetBounds(self.region, &mut bounds);
+        }
+        bounds.into()
+    }
+
+    /// The outline of the region as a path, or `None` if it is empty.
+    pub fn boundary_path(&self) -> Option<Path> {
+        let path = Path::new();
+        if unsafe { skia::SkiaSkRegionGetBoundaryPath(self.region, path.as_raw()) } {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    pub fn contains_point(&self, point: &Point2D<i32>) -> bool {
+        unsafe {
+            skia::SkiaSkRegionContainsPoint(self.region, point.x, point.y)
+        }
+    }
+
+    pub fn contains_rect<R>(&self, rect: R) -> bool where R: Into<skia::SkiaIRect> {
+        let rect = rect.into();
+        unsafe {
+            skia::SkiaSkRegionContainsRect(self.region, &rect)
+        }
+    }
+
+    pub fn contains_region(&self, other: &Region) -> bool {
+        unsafe {
+            skia::SkiaSkRegionContainsRegion(self.region, other.region)
+        }
+    }
+
+    pub fn intersects_rect<R>(&self, rect: R) -> bool where R: Into<skia::SkiaIRect> {
+        let rect = rect.into();
+        unsafe {
+            skia::SkiaSkRegionIntersectsRect(self.region, &rect)
+        }
+    }
+
+    pub fn intersects_region(&self, other: &Region) -> bool {
+        unsafe {
+            skia::SkiaSkRegionIntersectsRegion(self.region, other.region)
+        }
+    }
+
+    pub fn translate(&mut self, offset: &Vector2D<i32>) {
+        unsafe {
+            skia::SkiaSkRegionTranslate(self.region, offset.x, offset.y);
+        }
+    }
+
+    /// Replaces this region with the result of combining it with `rect`.
+    /// Returns whether the result is non-empty.
+    pub fn op_rect<R>(&mut self, rect: R, op: RegionOp) -> bool where R: Into<skia::SkiaIRect> {
+        let rect = rect.into();
+        unsafe {
+            skia::SkiaSkRegionOpRect(self.region, &rect, op as i32)
+        }
+    }
+
+    /// Replaces this region with the result of combining it with `other`.
+    /// Returns whether the result is non-empty.
+    pub fn op(&mut self, other: &Region, op: RegionOp) -> bool {
+        unsafe {
+            skia::SkiaSkRegionOpRegion(self.region, other.region, op as i32)
+        }
+    }
+
+    pub fn union(&mut self, other: &Region) -> bool {
+        self.op(other, RegionOp::Union)
+    }
+
+    pub fn intersect(&mut self, other: &Region) -> bool {
+        self.op(other, RegionOp::Intersect)
+    }
+
+    pub fn difference(&mut self, other: &Region) -> bool {
+        self.op(other, RegionOp::Difference)
+    }
+
+    pub fn xor(&mut self, other: &Region) -> bool {
+        self.op(other, RegionOp::Xor)
+    }
+
+    /// The rectangles that make up the region, sorted by y and then by x.
+    pub fn rects(&self) -> RegionRects<'_> {
+        RegionRects {
+            iterator: unsafe { skia::SkiaSkRegionIteratorCreate(self.region) },
+            phantom: PhantomData,
+        }
+    }
+}
+
+pub struct RegionRects<'a> {
+    iterator: skia::SkiaSkRegionIteratorRef,
+    phantom: PhantomData<&'a Region>,
+}
+
+impl<'a> Drop for RegionRects<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkRegionIteratorDelete(self.iterator);
+        }
+    }
+}
+
+impl<'a> Iterator for RegionRects<'a> {
+    type Item = Rect<i32>;
+
+    fn next(&mut self) -> Option<Rect<i32>> {
+        let mut rect = skia::SkiaIRect::default();
+        if unsafe { skia::SkiaSkRegionIteratorNext(self.iterator, &mut rect) } {
+            Some(rect.into())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use image_info::{ColorType, ImageInfo};
+use pixmap::Pixmap;
+use skia;
+
+use euclid::default::Size2D;
+use libc::{c_void, size_t};
+
+/// A resampling filter, matching `SkBitmapScaler::ResizeMethod`.
+///
+/// The first three choose a filter by quality; the rest name one directly.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResizeMethod {
+    Good = 0,
+    Better = 1,
+    Best = 2,
+    Box = 3,
+    Triangle = 4,
+    Lanczos3 = 5,
+    Hamming = 6,
+    Mitchell = 7,
+}
+
+/// Resamples `src` to `size` with `SkBitmapScaler`, using SIMD where the CPU
+/// supports it.
+///
+/// The source must be in the native 32-bit format. The result has the same
+/// format and alpha type, with rows packed `size.width * 4` bytes apart.
+/// Returns `None` if the source is in another format or either size is
+/// empty.
+pub fn resize(src: &Pixmap, size: Size2D<i32>, method: ResizeMethod) -> Option<Vec<u8>> {
+    if src.info().color_type != ColorType::N32 {
+        return None;
+    }
+    let dst_info = ImageInfo::new(size, ColorType::N32, src.info().alpha_type);
+    if src.info().is_empty() || dst_info.is_empty() {
+        return None;
+    }
+
+    let src_info = skia::SkiaImageInfo::from(*src.info());
+    let row_bytes = dst_info.min_row_bytes();
+    let mut result = vec![0; dst_info.compute_byte_size(row_bytes)];
+    if unsafe {
+        skia::SkiaSkBitmapScalerResize(&src_info,
+                                       src.pixels().as_ptr() as *const c_void,
+                                       src.row_bytes() as size_t,
+                                       method as i32,
+                                       size.width,
+                                       size.height,
+                                       result.as_mut_ptr() as *mut c_void,
+                                       row_bytes as size_t)
+    } {
+        Some(result)
+    } else {
+        None
+    }
+}
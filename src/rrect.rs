@@ -0,0 +1,154 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use path::Path;
+use skia;
+
+use euclid::default::{Point2D, Rect, Size2D, Transform2D};
+
+/// The corners of an `RRect`, in the order `SkRRect::Corner` stores them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corner {
+    UpperLeft = 0,
+    UpperRight = 1,
+    LowerRight = 2,
+    LowerLeft = 3,
+}
+
+/// A rectangle with an elliptical radius at each corner, like `SkRRect`.
+///
+/// Every constructor goes through Skia, so radii that don't fit in the
+/// rectangle are scaled down the same way CSS `border-radius` is, and the
+/// rectangle is sorted.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RRect {
+    rrect: skia::SkiaRRect,
+}
+
+impl RRect {
+    /// `radii` are the horizontal and vertical radii of each corner, in
+    /// `Corner` order.
+    pub fn new<R>(rect: R, radii: &[Size2D<f32>; 4]) -> RRect where R: Into<skia::SkiaRect> {
+        let mut rrect = skia::SkiaRRect {
+            rect: rect.into(),
+            radii: [skia::SkiaPoint::default(); 4],
+        };
+        for (radius, size) in rrect.radii.iter_mut().zip(radii.iter()) {
+            *radius = skia::SkiaPoint { x: size.width, y: size.height };
+        }
+        unsafe {
+            skia::SkiaSkRRectSetRectRadii(&mut rrect);
+        }
+        RRect { rrect }
+    }
+
+    pub fn from_rect<R>(rect: R) -> RRect where R: Into<skia::SkiaRect> {
+        RRect::new(rect, &[Size2D::zero(); 4])
+    }
+
+    pub fn from_oval<R>(oval: R) -> RRect where R: Into<skia::SkiaRect> {
+        let oval = oval.into();
+        let mut rrect = skia::SkiaRRect::default();
+        unsafe {
+            skia::SkiaSkRRectSetOval(&mut rrect, &oval);
+        }
+        RRect { rrect }
+    }
+
+    /// A rectangle with the same radii at every corner.
+    pub fn from_rect_xy<R>(rect: R, x_radius: f32, y_radius: f32) -> RRect
+                           where R: Into<skia::SkiaRect> {
+        let rect = rect.into();
+        let mut rrect = skia::SkiaRRect::default();
+        unsafe {
+            skia::SkiaSkRRectSetRectXY(&mut rrect, &rect, x_radius, y_radius);
+        }
+        RRect { rrect }
+    }
+
+    pub fn as_raw(&self) -> &skia::SkiaRRect {
+        &self.rrect
+    }
+
+    pub fn rect(&self) -> Rect<f32> {
+        self.rrect.rect.into()
+    }
+
+    pub fn radii(&self, corner: Corner) -> Size2D<f32> {
+        let radius = self.rrect.radii[corner as usize];
+        Size2D::new(radius.x, radius.y)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rrect.rect.left >= self.rrect.rect.right ||
+            self.rrect.rect.top >= self.rrect.rect.bottom
+    }
+
+    /// Whether every corner is square.
+    pub fn is_rect(&self) -> bool {
+        // SkRRect::kRect_Type
+        unsafe { skia::SkiaSkRRectGetType(&self.rrect) == 1 }
+    }
+
+    /// Whether the radii make the rrect an ellipse.
+    pub fn is_oval(&self) -> bool {
+        // SkRRect::kOval_Type
+        unsafe { skia::SkiaSkRRectGetType(&self.rrect) == 2 }
+    }
+
+    /// Moves the edges in by `dx` and `dy`, shrinking the radii to match.
+    pub fn inset(&self, dx: f32, dy: f32) -> RRect {
+        let mut rrect = self.rrect;
+        unsafe {
+            skia::SkiaSkRRectInset(&mut rrect, dx, dy);
+        }
+        RRect { rrect }
+    }
+
+    /// Moves the edges out by `dx` and `dy`, growing the radii to match.
+    pub fn outset(&self, dx: f32, dy: f32) -> RRect {
+        self.inset(-dx, -dy)
+    }
+
+    pub fn offset(&self, dx: f32, dy: f32) -> RRect {
+        let mut rrect = self.rrect;
+        rrect.rect.left += dx;
+        rrect.rect.top += dy;
+        rrect.rect.right += dx;
+        rrect.rect.bottom += dy;
+        RRect { rrect }
+    }
+
+    pub fn contains_rect<R>(&self, rect: R) -> bool where R: Into<skia::SkiaRect> {
+        let rect = rect.into();
+        unsafe {
+            skia::SkiaSkRRectContainsRect(&self.rrect, &rect)
+        }
+    }
+
+    pub fn contains_point(&self, point: &Point2D<f32>) -> bool {
+        unsafe {
+            skia::SkiaSkRRectContainsPoint(&self.rrect, point.x, point.y)
+        }
+    }
+
+    /// Maps the rrect through `transform`. Returns `None` unless the
+    /// transform only scales, translates and rotates by multiples of 90
+    /// degrees, since anything else doesn't produce an rrect.
+    pub fn transform(&self, transform: &Transform2D<f32>) -> Option<RRect> {
+        let matrix = skia::SkiaMatrix::from(*transform);
+        let mut rrect = self.rrect;
+        if unsafe { skia::SkiaSkRRectTransform(&mut rrect, &matrix) } {
+            Some(RRect { rrect })
+        } else {
+            None
+        }
+    }
+
+    pub fn to_path(&self) -> Path {
+        let mut path = Path::new();
+        path.add_rrect(self, true);
+        path
+    }
+}
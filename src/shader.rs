@@ -0,0 +1,283 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use blend_mode::BlendMode;
+use color::Color;
+use image::Image;
+use matrix::Matrix;
+use picture::Picture;
+use skia;
+
+use euclid::default::{Point2D, Size2D, Vector2D};
+use std::ptr;
+
+/// How a shader fills the area outside its bounds, matching
+/// `SkShader::TileMode`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TileMode {
+    /// Repeats the edge colour.
+    Clamp = 0,
+    Repeat = 1,
+    /// Repeats, flipping every other copy.
+    Mirror = 2,
+}
+
+/// The colour stops and options shared by every gradient.
+#[derive(Clone, Copy, Debug)]
+pub struct Gradient<'a> {
+    pub colors: &'a [Color],
+    /// The position of each colour from 0 to 1, or `None` to spread them
+    /// evenly. Positions out of order are treated as equal to the previous
+    /// one, as CSS requires.
+    pub positions: Option<&'a [f32]>,
+    /// Ignored by sweep gradients, which always clamp.
+    pub tile_mode: TileMode,
+    /// Interpolates premultiplied colours instead of unpremultiplied ones.
+    pub interpolate_in_premul: bool,
+    pub local_matrix: Option<Matrix>,
+}
+
+impl<'a> Gradient<'a> {
+    /// Evenly spread, clamped colours, interpolated unpremultiplied.
+    pub fn new(colors: &'a [Color]) -> Gradient<'a> {
+        Gradient {
+            colors,
+            positions: None,
+            tile_mode: TileMode::Clamp,
+            interpolate_in_premul: false,
+            local_matrix: None,
+        }
+    }
+
+    fn create<F>(&self, create: F) -> Option<Shader>
+                 where F: FnOnce(&skia::SkiaGradient) -> skia::SkiaSkShaderRef {
+        if self.colors.is_empty() ||
+           self.positions.is_some_and(|positions| positions.len() != self.colors.len()) {
+            return None;
+        }
+        let colors: Vec<u32> = self.colors.iter().map(|color| color.0).collect();
+        // Skia only pins each position to [0, 1], and lets a later stop that
+        // goes backwards overwrite the ramp before it.
+        let positions: Option<Vec<f32>> = self.positions.map(|positions| {
+            let mut max = 0.0f32;
+            positions.iter().map(|&position| {
+                if position > max {
+                    max = position.min(1.0);
+                }
+                max
+            }).collect()
+        });
+        let gradient = skia::SkiaGradient {
+            colors: colors.as_ptr(),
+            positions: positions.as_ref().map_or(ptr::null(), |positions| positions.as_ptr()),
+            count: colors.len() as i32,
+            tile_mode: self.tile_mode as i32,
+            flags: self.interpolate_in_premul as u32,
+            local_matrix: self.local_matrix.as_ref().map_or(ptr::null(), |matrix| matrix.as_raw()),
+        };
+        Shader::from_raw(create(&gradient))
+    }
+}
+
+/// The kind of noise made by `Shader::perlin_noise`, matching the SVG
+/// `feTurbulence` `type` attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoiseType {
+    FractalNoise,
+    /// Sums the absolute value of each octave, giving sharper ridges.
+    Turbulence,
+}
+
+/// The parameters of SVG `feTurbulence`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PerlinNoise {
+    pub noise_type: NoiseType,
+    /// The frequency in each direction, usually between 0 and 1.
+    pub base_frequency: Vector2D<f32>,
+    /// The number of octaves summed, each at double the frequency of the
+    /// last. Only the first 10 are summed, since later ones are too faint to be
+    /// seen.
+    pub octaves: i32,
+    pub seed: f32,
+    /// With `stitchTiles="stitch"`, the size of the tile the noise should
+    /// repeat seamlessly across.
+    pub stitch_tile: Option<Size2D<i32>>,
+}
+
+/// A reference counted `SkShader`, which gives a paint its colours in place
+/// of a single colour.
+pub struct Shader {
+    shader: skia::SkiaSkShaderRef,
+}
+
+impl Drop for Shader {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkShaderRelease(self.shader);
+        }
+    }
+}
+
+impl Clone for Shader {
+    fn clone(&self) -> Shader {
+        unsafe {
+            skia::SkiaSkShaderRetain(self.shader);
+        }
+        Shader {
+            shader: self.shader,
+        }
+    }
+}
+
+// Shaders are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for Shader {}
+unsafe impl Sync for Shader {}
+
+impl Shader {
+    fn from_raw(shader: skia::SkiaSkShaderRef) -> Option<Shader> {
+        if shader.is_null() {
+            return None;
+        }
+        Some(Shader { shader })
+    }
+
+    /// A gradient along the line from `start` to `end`, like CSS
+    /// `linear-gradient`.
+    pub fn linear_gradient(start: &Point2D<f32>,
+                           end: &Point2D<f32>,
+                           gradient: &Gradient)
+                           -> Option<Shader> {
+        let (start, end) = (skia::SkiaPoint::from(*start), skia::SkiaPoint::from(*end));
+        gradient.create(|gradient| unsafe {
+            skia::SkiaSkGradientShaderCreateLinear(&start, &end, gradient)
+        })
+    }
+
+    /// A circular gradient out from `center`, like CSS `radial-gradient`.
+    /// `radius` must be positive.
+    pub fn radial_gradient(center: &Point2D<f32>,
+                           radius: f32,
+                           gradient: &Gradient)
+                           -> Option<Shader> {
+        let center = skia::SkiaPoint::from(*center);
+        gradient.create(|gradient| unsafe {
+            skia::SkiaSkGradientShaderCreateRadial(&center, radius, gradient)
+        })
+    }
+
+    /// A gradient between two circles, as drawn by the SVG `radialGradient`
+    /// focal point model. The radii must differ.
+    pub fn two_point_radial_gradient(start: &Point2D<f32>,
+                                     start_radius: f32,
+                                     end: &Point2D<f32>,
+                                     end_radius: f32,
+                                     gradient: &Gradient)
+                                     -> Option<Shader> {
+        let (start, end) = (skia::SkiaPoint::from(*start), skia::SkiaPoint::from(*end));
+        gradient.create(|gradient| unsafe {
+            skia::SkiaSkGradientShaderCreateTwoPointRadial(&start,
+                                                           start_radius,
+                                                           &end,
+                                                           end_radius,
+                                                           gradient)
+        })
+    }
+
+    /// A gradient along the cone between two circles, as used by the canvas
+    /// `createRadialGradient`.
+    pub fn two_point_conical_gradient(start: &Point2D<f32>,
+                                      start_radius: f32,
+                                      end: &Point2D<f32>,
+                                      end_radius: f32,
+                                      gradient: &Gradient)
+                                      -> Option<Shader> {
+        let (start, end) = (skia::SkiaPoint::from(*start), skia::SkiaPoint::from(*end));
+        gradient.create(|gradient| unsafe {
+            skia::SkiaSkGradientShaderCreateTwoPointConical(&start,
+                                                            start_radius,
+                                                            &end,
+                                                            end_radius,
+                                                            gradient)
+        })
+    }
+
+    /// A gradient around `center`, clockwise from the positive x axis. CSS
+    /// `conic-gradient` starts at the top instead, so callers must rotate
+    /// by -90° about `center` through the gradient's `local_matrix`.
+    pub fn sweep_gradient(center: &Point2D<f32>, gradient: &Gradient) -> Option<Shader> {
+        let center = skia::SkiaPoint::from(*center);
+        gradient.create(|gradient| unsafe {
+            skia::SkiaSkGradientShaderCreateSweep(&center, gradient)
+        })
+    }
+
+    /// Fills with `image`, tiled in each direction by the given mode, as for
+    /// CSS `background-repeat`. A GPU-backed image is sampled as a texture
+    /// without being read back.
+    pub fn image(image: &Image,
+                 tile_x: TileMode,
+                 tile_y: TileMode,
+                 local_matrix: Option<&Matrix>)
+                 -> Option<Shader> {
+        let local_matrix = local_matrix.map_or(ptr::null(), |matrix| matrix.as_raw());
+        Shader::from_raw(unsafe {
+            skia::SkiaSkShaderCreateImage(image.as_raw(),
+                                          tile_x as i32,
+                                          tile_y as i32,
+                                          local_matrix)
+        })
+    }
+
+    /// Fills with `picture`, tiled like `image`, as for SVG `pattern`. The
+    /// picture is rasterized as needed for the scale it is drawn at.
+    pub fn picture(picture: &Picture,
+                   tile_x: TileMode,
+                   tile_y: TileMode,
+                   local_matrix: Option<&Matrix>)
+                   -> Option<Shader> {
+        let local_matrix = local_matrix.map_or(ptr::null(), |matrix| matrix.as_raw());
+        Shader::from_raw(unsafe {
+            skia::SkiaSkShaderCreatePicture(picture.as_raw(),
+                                            tile_x as i32,
+                                            tile_y as i32,
+                                            local_matrix)
+        })
+    }
+
+    /// SVG `feTurbulence`, as a shader. Returns `None` if a frequency or the
+    /// number of octaves is negative.
+    pub fn perlin_noise(noise: &PerlinNoise) -> Option<Shader> {
+        let tile = noise.stitch_tile.unwrap_or_else(Size2D::zero);
+        Shader::from_raw(unsafe {
+            skia::SkiaSkShaderCreatePerlinNoise(noise.noise_type == NoiseType::Turbulence,
+                                                noise.base_frequency.x,
+                                                noise.base_frequency.y,
+                                                noise.octaves,
+                                                noise.seed,
+                                                tile.width,
+                                                tile.height)
+        })
+    }
+
+    /// Blends the colours of `src` over those of `dst` with `mode`.
+    /// `SkComposeShader` has no GPU implementation in this version of Skia,
+    /// so it only draws on raster surfaces.
+    pub fn compose(dst: &Shader, src: &Shader, mode: BlendMode) -> Shader {
+        Shader::from_raw(unsafe {
+            skia::SkiaSkShaderCreateCompose(dst.shader, src.shader, mode as i32)
+        }).expect("SkComposeShader failed")
+    }
+
+    /// This shader with `local_matrix` applied before its own.
+    pub fn with_local_matrix(&self, local_matrix: &Matrix) -> Shader {
+        Shader::from_raw(unsafe {
+            skia::SkiaSkShaderCreateLocalMatrix(self.shader, local_matrix.as_raw())
+        }).expect("SkShader::CreateLocalMatrixShader failed")
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkShaderRef {
+        self.shader
+    }
+}
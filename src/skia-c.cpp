@@ -5,6 +5,310 @@
 #include "skia-c.h"
 
 #include "gl/GrGLUtil.h"
+#include "etc1.h"
+#include "GrDrawTargetCaps.h"
+#include "GrGpu.h"
+#include "GrTexture.h"
+#include "ktx.h"
+#include "Sk1DPathEffect.h"
+#include "Sk2DPathEffect.h"
+#include "SkArithmeticMode.h"
+#include "SkAvoidXfermode.h"
+#include "SkBitmapProcState.h"
+#include "SkBitmapScaler.h"
+#include "SkBlurDrawLooper.h"
+#include "SkBlurImageFilter.h"
+#include "SkBlurMaskFilter.h"
+#include "SkCamera.h"
+#include "SkCanvas.h"
+#include "SkColorFilterImageFilter.h"
+#include "SkColorMatrix.h"
+#include "SkColorMatrixFilter.h"
+#include "SkColorPriv.h"
+#include "SkColorTable.h"
+#include "SkComposeImageFilter.h"
+#include "SkComposeShader.h"
+#include "SkConfig8888.h"
+#include "SkCornerPathEffect.h"
+#include "SkDashPathEffect.h"
+#include "SkData.h"
+#include "SkDecodingImageGenerator.h"
+#include "SkDiscardableMemoryPool.h"
+#include "SkDiscretePathEffect.h"
+#include "SkDisplacementMapEffect.h"
+#include "SkDropShadowImageFilter.h"
+#include "SkFontConfigInterface.h"
+#include "SkFontMgr.h"
+#include "SkFontStyle.h"
+#include "SkGeometry.h"
+#include "SkGr.h"
+#include "SkGradientShader.h"
+#include "SkGrPixelRef.h"
+#include "SkImage.h"
+#include "SkImage_Base.h"
+#include "SkImageDecoder.h"
+#include "SkImageEncoder.h"
+#include "SkImageGenerator.h"
+#include "SkImagePriv.h"
+#include "SkLayerDrawLooper.h"
+#include "SkLerpXfermode.h"
+#include "SkLightingImageFilter.h"
+#include "SkLumaColorFilter.h"
+#include "SkMallocPixelRef.h"
+#include "SkMatrix44.h"
+#include "SkMatrixConvolutionImageFilter.h"
+#include "SkMergeImageFilter.h"
+#include "SkMorphologyImageFilter.h"
+#include "SkMovie.h"
+#include "SkOffsetImageFilter.h"
+#include "SkPaint.h"
+#include "SkPath.h"
+#include "SkPathMeasure.h"
+#include "SkPathOps.h"
+#include "SkPerlinNoiseShader.h"
+#include "SkPicture.h"
+#include "SkPictureRecorder.h"
+#include "SkPixelXorXfermode.h"
+#include "SkRegion.h"
+#include "SkRRect.h"
+#include "SkStippleMaskFilter.h"
+#include "SkStream.h"
+#include "SkStrokeRec.h"
+#include "SkSurface.h"
+#include "SkTableColorFilter.h"
+#include "SkTableMaskFilter.h"
+#include "SkTDArray.h"
+#include "SkTextureCompressor.h"
+#include "SkTileImageFilter.h"
+#include "SkTypeface.h"
+#include "SkUnPreMultiply.h"
+#include "SkXfermodeImageFilter.h"
+
+static SkRect
+ToSkRect(const SkiaRect* aRect) {
+    return SkRect::MakeLTRB(aRect->left, aRect->top, aRect->right, aRect->bottom);
+}
+
+static SkPoint
+ToSkPoint(const SkiaPoint* aPoint) {
+    return SkPoint::Make(aPoint->x, aPoint->y);
+}
+
+static void
+FromSkRect(const SkRect& aRect, SkiaRect* aResult) {
+    aResult->left = aRect.fLeft;
+    aResult->top = aRect.fTop;
+    aResult->right = aRect.fRight;
+    aResult->bottom = aRect.fBottom;
+}
+
+static SkMatrix
+ToSkMatrix(const SkiaMatrix* aMatrix) {
+    const float* v = aMatrix->values;
+    SkMatrix matrix;
+    matrix.setAll(v[0], v[1], v[2], v[3], v[4], v[5], v[6], v[7], v[8]);
+    return matrix;
+}
+
+static SkPath::Direction
+ToSkPathDirection(bool aClockwise) {
+    return aClockwise ? SkPath::kCW_Direction : SkPath::kCCW_Direction;
+}
+
+static SkIRect
+ToSkIRect(const SkiaIRect* aRect) {
+    return SkIRect::MakeLTRB(aRect->left, aRect->top, aRect->right, aRect->bottom);
+}
+
+static void
+FromSkIRect(const SkIRect& aRect, SkiaIRect* aResult) {
+    aResult->left = aRect.fLeft;
+    aResult->top = aRect.fTop;
+    aResult->right = aRect.fRight;
+    aResult->bottom = aRect.fBottom;
+}
+
+static SkRRect
+ToSkRRect(const SkiaRRect* aRRect) {
+    SkVector radii[4];
+    for (int i = 0; i < 4; i++) {
+        radii[i].set(aRRect->radii[i].x, aRRect->radii[i].y);
+    }
+    SkRRect rrect;
+    rrect.setRectRadii(ToSkRect(&aRRect->rect), radii);
+    return rrect;
+}
+
+static void
+FromSkRRect(const SkRRect& aRRect, SkiaRRect* aResult) {
+    FromSkRect(aRRect.rect(), &aResult->rect);
+    for (int i = 0; i < 4; i++) {
+        const SkVector& radii = aRRect.radii(static_cast<SkRRect::Corner>(i));
+        aResult->radii[i].x = radii.fX;
+        aResult->radii[i].y = radii.fY;
+    }
+}
+
+static void
+FromSkMatrix(const SkMatrix& aMatrix, SkiaMatrix* aResult) {
+    for (int i = 0; i < 9; i++) {
+        aResult->values[i] = aMatrix.get(i);
+    }
+}
+
+static SkMatrix44
+ToSkMatrix44(const SkiaMatrix44* aMatrix) {
+    SkMatrix44 matrix(SkMatrix44::kUninitialized_Constructor);
+    matrix.setColMajorf(aMatrix->values);
+    return matrix;
+}
+
+static void
+FromSkMatrix44(const SkMatrix44& aMatrix, SkiaMatrix44* aResult) {
+    aMatrix.asColMajorf(aResult->values);
+}
+
+static SkImageInfo
+ToSkImageInfo(const SkiaImageInfo* aInfo) {
+    return SkImageInfo::Make(aInfo->width, aInfo->height,
+                             static_cast<SkColorType>(aInfo->colorType),
+                             static_cast<SkAlphaType>(aInfo->alphaType));
+}
+
+static void
+FromSkImageInfo(const SkImageInfo& aInfo, SkiaImageInfo* aResult) {
+    aResult->width = aInfo.fWidth;
+    aResult->height = aInfo.fHeight;
+    aResult->colorType = aInfo.fColorType;
+    aResult->alphaType = aInfo.fAlphaType;
+}
+
+static bool
+Is32BitColorType(SkColorType aColorType) {
+    return aColorType == kRGBA_8888_SkColorType || aColorType == kBGRA_8888_SkColorType;
+}
+
+// Converts between the two 32-bit layouts and any pair of alpha types.
+static bool
+Convert32BitPixels(const SkImageInfo& aSrcInfo, const void* aSrcPixels, size_t aSrcRowBytes,
+                   const SkImageInfo& aDstInfo, void* aDstPixels, size_t aDstRowBytes) {
+    SkSrcPixelInfo src;
+    src.fColorType = aSrcInfo.fColorType;
+    src.fAlphaType = aSrcInfo.fAlphaType;
+    src.fRowBytes = aSrcRowBytes;
+    src.fPixels = aSrcPixels;
+    SkDstPixelInfo dst;
+    dst.fColorType = aDstInfo.fColorType;
+    dst.fAlphaType = aDstInfo.fAlphaType;
+    dst.fRowBytes = aDstRowBytes;
+    dst.fPixels = aDstPixels;
+    return src.convertPixelsTo(&dst, aSrcInfo.fWidth, aSrcInfo.fHeight);
+}
+
+// Holds the local matrix of an SkiaGradient, if it has one, for the
+// duration of a SkGradientShader::Create* call.
+class SkiaLocalMatrix {
+public:
+    explicit SkiaLocalMatrix(const SkiaMatrix* aMatrix)
+        : fMatrix(aMatrix ? ToSkMatrix(aMatrix) : SkMatrix::I())
+        , fHasMatrix(aMatrix != NULL) {}
+
+    const SkMatrix* get() const { return fHasMatrix ? &fMatrix : NULL; }
+
+private:
+    SkMatrix fMatrix;
+    bool fHasMatrix;
+};
+
+// Holds the crop rect of an image filter, if it has one, for the duration of
+// an SkImageFilter factory call.
+class SkiaOptionalCropRect {
+public:
+    explicit SkiaOptionalCropRect(const SkiaCropRect* aCropRect)
+        : fCropRect(aCropRect ? ToSkRect(&aCropRect->rect) : SkRect::MakeEmpty(),
+                    aCropRect ? aCropRect->edges : 0)
+        , fHasCropRect(aCropRect != NULL) {}
+
+    const SkImageFilter::CropRect* get() const { return fHasCropRect ? &fCropRect : NULL; }
+
+private:
+    SkImageFilter::CropRect fCropRect;
+    bool fHasCropRect;
+};
+
+static bool
+IsValidXfermode(int32_t aMode) {
+    return aMode >= 0 && aMode <= SkXfermode::kLastMode;
+}
+
+// Forwards everything written to it to a SkiaWriteCallback.
+class SkiaCallbackWStream : public SkWStream {
+public:
+    SkiaCallbackWStream(SkiaWriteCallback aCallback, void* aClosure)
+        : mCallback(aCallback)
+        , mClosure(aClosure)
+        , mBytesWritten(0) {}
+
+    virtual bool write(const void* aBuffer, size_t aSize) SK_OVERRIDE {
+        if (!mCallback(mClosure, aBuffer, aSize)) {
+            return false;
+        }
+        mBytesWritten += aSize;
+        return true;
+    }
+
+    virtual size_t bytesWritten() const SK_OVERRIDE {
+        return mBytesWritten;
+    }
+
+private:
+    SkiaWriteCallback mCallback;
+    void* mClosure;
+    size_t mBytesWritten;
+};
+
+// An SkImageGenerator whose work is done by SkiaImageGeneratorCallbacks.
+class SkiaImageGenerator : public SkImageGenerator {
+public:
+    SkiaImageGenerator(const SkiaImageGeneratorCallbacks* aCallbacks, void* aClosure)
+        : mCallbacks(*aCallbacks)
+        , mClosure(aClosure) {}
+
+    virtual ~SkiaImageGenerator() {
+        mCallbacks.destroy(mClosure);
+    }
+
+protected:
+    virtual SkData* onRefEncodedData() SK_OVERRIDE {
+        size_t length = 0;
+        const void* data = mCallbacks.getEncodedData(mClosure, &length);
+        return data ? SkData::NewWithCopy(data, length) : NULL;
+    }
+
+    virtual bool onGetInfo(SkImageInfo* aInfo) SK_OVERRIDE {
+        SkiaImageInfo info;
+        if (!mCallbacks.getInfo(mClosure, &info)) {
+            return false;
+        }
+        *aInfo = ToSkImageInfo(&info);
+        return true;
+    }
+
+    virtual bool onGetPixels(const SkImageInfo& aInfo, void* aPixels, size_t aRowBytes,
+                             SkPMColor aColorTable[], int* aColorTableCount) SK_OVERRIDE {
+        // The callbacks have no way to supply a colour table.
+        if (aInfo.fColorType == kIndex_8_SkColorType) {
+            return false;
+        }
+        SkiaImageInfo info;
+        FromSkImageInfo(aInfo, &info);
+        return mCallbacks.getPixels(mClosure, &info, aPixels, aRowBytes);
+    }
+
+private:
+    SkiaImageGeneratorCallbacks mCallbacks;
+    void* mClosure;
+};
 
 extern "C" SkiaGrGLInterfaceRef
 SkiaGrGLCreateNativeInterface() {
@@ -50,3 +354,2732 @@ extern "C" void
 SkiaGrContextRelease(SkiaGrContextRef aContext) {
     SkSafeUnref(static_cast<GrContext*>(aContext));
 }
+
+extern "C" SkiaSkPathRef
+SkiaSkPathCreate() {
+    return new SkPath();
+}
+
+extern "C" SkiaSkPathRef
+SkiaSkPathClone(SkiaSkPathRef aPath) {
+    return new SkPath(*static_cast<SkPath*>(aPath));
+}
+
+extern "C" void
+SkiaSkPathDelete(SkiaSkPathRef aPath) {
+    delete static_cast<SkPath*>(aPath);
+}
+
+extern "C" void
+SkiaSkPathReset(SkiaSkPathRef aPath) {
+    static_cast<SkPath*>(aPath)->reset();
+}
+
+extern "C" int32_t
+SkiaSkPathGetFillType(SkiaSkPathRef aPath) {
+    return static_cast<SkPath*>(aPath)->getFillType();
+}
+
+extern "C" void
+SkiaSkPathSetFillType(SkiaSkPathRef aPath, int32_t aFillType) {
+    static_cast<SkPath*>(aPath)->setFillType(static_cast<SkPath::FillType>(aFillType));
+}
+
+extern "C" bool
+SkiaSkPathIsEmpty(SkiaSkPathRef aPath) {
+    return static_cast<SkPath*>(aPath)->isEmpty();
+}
+
+extern "C" void
+SkiaSkPathGetBounds(SkiaSkPathRef aPath, SkiaRect* aBounds) {
+    FromSkRect(static_cast<SkPath*>(aPath)->getBounds(), aBounds);
+}
+
+extern "C" bool
+SkiaSkPathContains(SkiaSkPathRef aPath, float x, float y) {
+    return static_cast<SkPath*>(aPath)->contains(x, y);
+}
+
+extern "C" void
+SkiaSkPathMoveTo(SkiaSkPathRef aPath, float x, float y) {
+    static_cast<SkPath*>(aPath)->moveTo(x, y);
+}
+
+extern "C" void
+SkiaSkPathLineTo(SkiaSkPathRef aPath, float x, float y) {
+    static_cast<SkPath*>(aPath)->lineTo(x, y);
+}
+
+extern "C" void
+SkiaSkPathQuadTo(SkiaSkPathRef aPath, float x1, float y1, float x2, float y2) {
+    static_cast<SkPath*>(aPath)->quadTo(x1, y1, x2, y2);
+}
+
+extern "C" void
+SkiaSkPathConicTo(SkiaSkPathRef aPath, float x1, float y1, float x2, float y2, float weight) {
+    static_cast<SkPath*>(aPath)->conicTo(x1, y1, x2, y2, weight);
+}
+
+extern "C" void
+SkiaSkPathCubicTo(SkiaSkPathRef aPath, float x1, float y1, float x2, float y2, float x3, float y3) {
+    static_cast<SkPath*>(aPath)->cubicTo(x1, y1, x2, y2, x3, y3);
+}
+
+extern "C" void
+SkiaSkPathClose(SkiaSkPathRef aPath) {
+    static_cast<SkPath*>(aPath)->close();
+}
+
+extern "C" void
+SkiaSkPathAddRect(SkiaSkPathRef aPath, const SkiaRect* aRect, bool aClockwise) {
+    static_cast<SkPath*>(aPath)->addRect(ToSkRect(aRect), ToSkPathDirection(aClockwise));
+}
+
+extern "C" void
+SkiaSkPathAddOval(SkiaSkPathRef aPath, const SkiaRect* aOval, bool aClockwise) {
+    static_cast<SkPath*>(aPath)->addOval(ToSkRect(aOval), ToSkPathDirection(aClockwise));
+}
+
+extern "C" void
+SkiaSkPathAddCircle(SkiaSkPathRef aPath, float x, float y, float radius, bool aClockwise) {
+    static_cast<SkPath*>(aPath)->addCircle(x, y, radius, ToSkPathDirection(aClockwise));
+}
+
+extern "C" void
+SkiaSkPathAddPath(SkiaSkPathRef aPath, SkiaSkPathRef aOther) {
+    static_cast<SkPath*>(aPath)->addPath(*static_cast<SkPath*>(aOther));
+}
+
+extern "C" void
+SkiaSkPathTransform(SkiaSkPathRef aPath, const SkiaMatrix* aMatrix) {
+    static_cast<SkPath*>(aPath)->transform(ToSkMatrix(aMatrix));
+}
+
+extern "C" bool
+SkiaSkPathOp(SkiaSkPathRef aOne, SkiaSkPathRef aTwo, int32_t aOp, SkiaSkPathRef aResult) {
+    return Op(*static_cast<SkPath*>(aOne),
+              *static_cast<SkPath*>(aTwo),
+              static_cast<SkPathOp>(aOp),
+              static_cast<SkPath*>(aResult));
+}
+
+extern "C" bool
+SkiaSkPathSimplify(SkiaSkPathRef aPath, SkiaSkPathRef aResult) {
+    return Simplify(*static_cast<SkPath*>(aPath), static_cast<SkPath*>(aResult));
+}
+
+static void
+AddCubicExtrema(const SkPoint aPts[4], SkTDArray<SkPoint>* aExtrema) {
+    SkScalar tValues[4];
+    int count = SkFindCubicExtrema(aPts[0].fX, aPts[1].fX, aPts[2].fX, aPts[3].fX, tValues);
+    count += SkFindCubicExtrema(aPts[0].fY, aPts[1].fY, aPts[2].fY, aPts[3].fY, &tValues[count]);
+    for (int i = 0; i < count; i++) {
+        SkEvalCubicAt(aPts, tValues[i], aExtrema->append(), NULL, NULL);
+    }
+}
+
+static void
+AddConicExtrema(const SkPoint aPts[3], SkScalar aWeight, SkTDArray<SkPoint>* aExtrema) {
+    SkConic conic;
+    conic.set(aPts, aWeight);
+    SkScalar t;
+    if (conic.findXExtrema(&t)) {
+        conic.evalAt(t, aExtrema->append());
+    }
+    if (conic.findYExtrema(&t)) {
+        conic.evalAt(t, aExtrema->append());
+    }
+}
+
+// This version of SkPathOps predates TightBounds(), so compute the bounds
+// from the on-curve points and the curves' extrema rather than from the
+// control points as SkPath::getBounds() does.
+extern "C" bool
+SkiaSkPathTightBounds(SkiaSkPathRef aPath, SkiaRect* aBounds) {
+    const SkPath* path = static_cast<SkPath*>(aPath);
+    if (!path->isFinite()) {
+        return false;
+    }
+
+    SkTDArray<SkPoint> points;
+    SkPath::Iter iter(*path, false);
+    SkPoint pts[4];
+    SkPath::Verb verb;
+    while ((verb = iter.next(pts, false)) != SkPath::kDone_Verb) {
+        switch (verb) {
+            case SkPath::kLine_Verb:
+                points.append(2, pts);
+                break;
+            case SkPath::kQuad_Verb:
+                *points.append() = pts[0];
+                *points.append() = pts[2];
+                AddConicExtrema(pts, SK_Scalar1, &points);
+                break;
+            case SkPath::kConic_Verb:
+                *points.append() = pts[0];
+                *points.append() = pts[2];
+                AddConicExtrema(pts, iter.conicWeight(), &points);
+                break;
+            case SkPath::kCubic_Verb:
+                *points.append() = pts[0];
+                *points.append() = pts[3];
+                AddCubicExtrema(pts, &points);
+                break;
+            default:
+                // Moves that don't start a segment and closes add no area.
+                break;
+        }
+    }
+
+    SkRect bounds;
+    bounds.setBounds(points.begin(), points.count());
+    FromSkRect(bounds, aBounds);
+    return true;
+}
+
+// SkPathMeasure skips conics, so they are measured as the quads that
+// approximate them to within a quarter of a unit, as hairlines draw them.
+static SkPath
+ConicsToQuads(const SkPath& aPath) {
+    if (!(aPath.getSegmentMasks() & SkPath::kConic_SegmentMask)) {
+        return aPath;
+    }
+
+    SkPath result;
+    result.setFillType(aPath.getFillType());
+    SkPath::RawIter iter(aPath);
+    SkAutoConicToQuads converter;
+    SkPoint pts[4];
+    SkPath::Verb verb;
+    while ((verb = iter.next(pts)) != SkPath::kDone_Verb) {
+        switch (verb) {
+            case SkPath::kMove_Verb:
+                result.moveTo(pts[0]);
+                break;
+            case SkPath::kLine_Verb:
+                result.lineTo(pts[1]);
+                break;
+            case SkPath::kQuad_Verb:
+                result.quadTo(pts[1], pts[2]);
+                break;
+            case SkPath::kConic_Verb: {
+                const SkPoint* quadPts = converter.computeQuads(pts, iter.conicWeight(), SK_Scalar1 / 4);
+                for (int i = 0; i < converter.countQuads(); ++i) {
+                    result.quadTo(quadPts[1], quadPts[2]);
+                    quadPts += 2;
+                }
+                break;
+            }
+            case SkPath::kCubic_Verb:
+                result.cubicTo(pts[1], pts[2], pts[3]);
+                break;
+            case SkPath::kClose_Verb:
+                result.close();
+                break;
+            default:
+                break;
+        }
+    }
+    return result;
+}
+
+// SkPathMeasure only keeps a pointer to the path it measures, so these own a
+// copy of it for as long as the measure is alive.
+struct SkiaPathMeasure {
+    SkiaPathMeasure(const SkPath& aPath, bool aForceClosed)
+        : fPath(ConicsToQuads(aPath))
+        , fMeasure(fPath, aForceClosed)
+        , fStarted(false) {
+    }
+
+    SkPath fPath;
+    SkPathMeasure fMeasure;
+    bool fStarted;
+};
+
+struct SkiaContourMeasure {
+    SkiaContourMeasure(const SkPath& aContour, bool aClosed)
+        : fContour(aContour)
+        , fMeasure(fContour, aClosed)
+        , fClosed(aClosed) {
+    }
+
+    SkPath fContour;
+    SkPathMeasure fMeasure;
+    bool fClosed;
+};
+
+extern "C" SkiaSkPathMeasureRef
+SkiaSkPathMeasureCreate(SkiaSkPathRef aPath, bool aForceClosed) {
+    return new SkiaPathMeasure(*static_cast<SkPath*>(aPath), aForceClosed);
+}
+
+extern "C" void
+SkiaSkPathMeasureDelete(SkiaSkPathMeasureRef aPathMeasure) {
+    delete static_cast<SkiaPathMeasure*>(aPathMeasure);
+}
+
+// Like SkPathMeasure::nextContour(), this stops at the first contour with no
+// length.
+extern "C" SkiaSkContourMeasureRef
+SkiaSkPathMeasureNextContour(SkiaSkPathMeasureRef aPathMeasure) {
+    SkiaPathMeasure* pathMeasure = static_cast<SkiaPathMeasure*>(aPathMeasure);
+    SkPathMeasure& measure = pathMeasure->fMeasure;
+    if (pathMeasure->fStarted) {
+        if (!measure.nextContour()) {
+            return NULL;
+        }
+    }
+    pathMeasure->fStarted = true;
+
+    SkScalar length = measure.getLength();
+    if (length <= 0) {
+        return NULL;
+    }
+
+    SkPath contour;
+    if (!measure.getSegment(0, length, &contour, true)) {
+        return NULL;
+    }
+    return new SkiaContourMeasure(contour, measure.isClosed());
+}
+
+extern "C" void
+SkiaSkContourMeasureDelete(SkiaSkContourMeasureRef aContourMeasure) {
+    delete static_cast<SkiaContourMeasure*>(aContourMeasure);
+}
+
+extern "C" float
+SkiaSkContourMeasureGetLength(SkiaSkContourMeasureRef aContourMeasure) {
+    return static_cast<SkiaContourMeasure*>(aContourMeasure)->fMeasure.getLength();
+}
+
+extern "C" bool
+SkiaSkContourMeasureIsClosed(SkiaSkContourMeasureRef aContourMeasure) {
+    return static_cast<SkiaContourMeasure*>(aContourMeasure)->fClosed;
+}
+
+extern "C" bool
+SkiaSkContourMeasureGetPosTan(SkiaSkContourMeasureRef aContourMeasure,
+                              float aDistance,
+                              SkiaPoint* aPosition,
+                              SkiaPoint* aTangent) {
+    SkPoint position;
+    SkVector tangent;
+    if (!static_cast<SkiaContourMeasure*>(aContourMeasure)->fMeasure.getPosTan(aDistance, &position, &tangent)) {
+        return false;
+    }
+    aPosition->x = position.fX;
+    aPosition->y = position.fY;
+    aTangent->x = tangent.fX;
+    aTangent->y = tangent.fY;
+    return true;
+}
+
+extern "C" bool
+SkiaSkContourMeasureGetSegment(SkiaSkContourMeasureRef aContourMeasure,
+                               float aStartDistance,
+                               float aStopDistance,
+                               SkiaSkPathRef aResult,
+                               bool aStartWithMoveTo) {
+    return static_cast<SkiaContourMeasure*>(aContourMeasure)->fMeasure.getSegment(aStartDistance,
+                                                                                  aStopDistance,
+                                                                                  static_cast<SkPath*>(aResult),
+                                                                                  aStartWithMoveTo);
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreateDash(const float aIntervals[], int32_t aCount, float aPhase) {
+    // SkDashPathEffect only asserts on these, so reject bad input here.
+    if (aCount < 2 || (aCount & 1) != 0) {
+        return NULL;
+    }
+    SkScalar intervalLength = 0;
+    for (int32_t i = 0; i < aCount; i++) {
+        if (!SkScalarIsFinite(aIntervals[i]) || aIntervals[i] < 0) {
+            return NULL;
+        }
+        intervalLength += aIntervals[i];
+    }
+    if (!SkScalarIsFinite(intervalLength) || intervalLength <= 0 || !SkScalarIsFinite(aPhase)) {
+        return NULL;
+    }
+    return SkDashPathEffect::Create(aIntervals, aCount, aPhase);
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreateCorner(float aRadius) {
+    if (!SkScalarIsFinite(aRadius) || aRadius <= 0) {
+        return NULL;
+    }
+    return SkCornerPathEffect::Create(aRadius);
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreateDiscrete(float aSegmentLength, float aDeviation, uint32_t aSeed) {
+    // Very short segments would make filterPath emit an unbounded number of
+    // points.
+    if (!SkScalarIsFinite(aSegmentLength) || aSegmentLength < SK_Scalar1 / 16 ||
+        !SkScalarIsFinite(aDeviation)) {
+        return NULL;
+    }
+    return SkDiscretePathEffect::Create(aSegmentLength, aDeviation, aSeed);
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreatePath1D(SkiaSkPathRef aPath, float aAdvance, float aPhase, int32_t aStyle) {
+    const SkPath* path = static_cast<SkPath*>(aPath);
+    if (path->isEmpty() || !SkScalarIsFinite(aAdvance) || aAdvance <= 0 ||
+        !SkScalarIsFinite(aPhase) || aStyle < 0 ||
+        aStyle >= SkPath1DPathEffect::kStyleCount) {
+        return NULL;
+    }
+    return SkPath1DPathEffect::Create(*path,
+                                      aAdvance,
+                                      aPhase,
+                                      static_cast<SkPath1DPathEffect::Style>(aStyle));
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreateLine2D(float aWidth, const SkiaMatrix* aMatrix) {
+    SkMatrix matrix = ToSkMatrix(aMatrix);
+    if (!SkScalarIsFinite(aWidth) || aWidth < 0 || matrix.hasPerspective() ||
+        !matrix.invert(NULL)) {
+        return NULL;
+    }
+    return SkLine2DPathEffect::Create(aWidth, matrix);
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreatePath2D(const SkiaMatrix* aMatrix, SkiaSkPathRef aPath) {
+    SkMatrix matrix = ToSkMatrix(aMatrix);
+    const SkPath* path = static_cast<SkPath*>(aPath);
+    if (path->isEmpty() || matrix.hasPerspective() || !matrix.invert(NULL)) {
+        return NULL;
+    }
+    return SkPath2DPathEffect::Create(matrix, *path);
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreateCompose(SkiaSkPathEffectRef aOuter, SkiaSkPathEffectRef aInner) {
+    return SkComposePathEffect::Create(static_cast<SkPathEffect*>(aOuter),
+                                       static_cast<SkPathEffect*>(aInner));
+}
+
+extern "C" SkiaSkPathEffectRef
+SkiaSkPathEffectCreateSum(SkiaSkPathEffectRef aFirst, SkiaSkPathEffectRef aSecond) {
+    return SkSumPathEffect::Create(static_cast<SkPathEffect*>(aFirst),
+                                   static_cast<SkPathEffect*>(aSecond));
+}
+
+extern "C" void
+SkiaSkPathEffectRetain(SkiaSkPathEffectRef aPathEffect) {
+    SkSafeRef(static_cast<SkPathEffect*>(aPathEffect));
+}
+
+extern "C" void
+SkiaSkPathEffectRelease(SkiaSkPathEffectRef aPathEffect) {
+    SkSafeUnref(static_cast<SkPathEffect*>(aPathEffect));
+}
+
+extern "C" bool
+SkiaSkPathEffectFilterPath(SkiaSkPathEffectRef aPathEffect,
+                           SkiaSkPathRef aPath,
+                           SkiaSkPathRef aResult) {
+    const SkPathEffect* pathEffect = static_cast<SkPathEffect*>(aPathEffect);
+    const SkPath* path = static_cast<SkPath*>(aPath);
+    SkPath* result = static_cast<SkPath*>(aResult);
+    // Start from a hairline so that effects which only apply to strokes, such
+    // as dashing, still run.
+    SkStrokeRec rec(SkStrokeRec::kHairline_InitStyle);
+    SkPath filtered;
+    if (!pathEffect->filterPath(&filtered, *path, &rec, NULL)) {
+        return false;
+    }
+    // SkLine2DPathEffect asks for its lines to be stroked, so hand back the
+    // outline it would be drawn with.
+    if (!rec.applyToPath(result, filtered)) {
+        result->swap(filtered);
+    }
+    return true;
+}
+
+static void
+SetStrokeParams(SkPaint* aPaint,
+                float aWidth,
+                int32_t aCap,
+                int32_t aJoin,
+                float aMiterLimit,
+                SkiaSkPathEffectRef aPathEffect) {
+    aPaint->setStrokeWidth(aWidth);
+    aPaint->setStrokeCap(static_cast<SkPaint::Cap>(aCap));
+    aPaint->setStrokeJoin(static_cast<SkPaint::Join>(aJoin));
+    aPaint->setStrokeMiter(aMiterLimit);
+    aPaint->setPathEffect(static_cast<SkPathEffect*>(aPathEffect));
+}
+
+// Returns false, leaving the result empty, for hairline strokes, since those
+// have no outline to fill.
+extern "C" bool
+SkiaSkPathStroke(SkiaSkPathRef aPath,
+                 float aWidth,
+                 int32_t aCap,
+                 int32_t aJoin,
+                 float aMiterLimit,
+                 SkiaSkPathEffectRef aPathEffect,
+                 SkiaSkPathRef aResult) {
+    SkPaint paint;
+    paint.setStyle(SkPaint::kStroke_Style);
+    SetStrokeParams(&paint, aWidth, aCap, aJoin, aMiterLimit, aPathEffect);
+
+    SkPath* result = static_cast<SkPath*>(aResult);
+    if (!paint.getFillPath(*static_cast<SkPath*>(aPath), result)) {
+        result->reset();
+        return false;
+    }
+    return true;
+}
+
+extern "C" bool
+SkiaSkPathStrokeContains(SkiaSkPathRef aPath,
+                         float aWidth,
+                         int32_t aCap,
+                         int32_t aJoin,
+                         float aMiterLimit,
+                         SkiaSkPathEffectRef aPathEffect,
+                         float x,
+                         float y) {
+    SkPath outline;
+    if (!SkiaSkPathStroke(aPath, aWidth, aCap, aJoin, aMiterLimit, aPathEffect, &outline)) {
+        return false;
+    }
+    return outline.contains(x, y);
+}
+
+extern "C" SkiaSkRegionRef
+SkiaSkRegionCreate() {
+    return new SkRegion();
+}
+
+extern "C" SkiaSkRegionRef
+SkiaSkRegionClone(SkiaSkRegionRef aRegion) {
+    return new SkRegion(*static_cast<SkRegion*>(aRegion));
+}
+
+extern "C" void
+SkiaSkRegionDelete(SkiaSkRegionRef aRegion) {
+    delete static_cast<SkRegion*>(aRegion);
+}
+
+extern "C" bool
+SkiaSkRegionEquals(SkiaSkRegionRef aRegion, SkiaSkRegionRef aOther) {
+    return *static_cast<SkRegion*>(aRegion) == *static_cast<SkRegion*>(aOther);
+}
+
+extern "C" bool
+SkiaSkRegionSetEmpty(SkiaSkRegionRef aRegion) {
+    return static_cast<SkRegion*>(aRegion)->setEmpty();
+}
+
+extern "C" bool
+SkiaSkRegionSetRect(SkiaSkRegionRef aRegion, const SkiaIRect* aRect) {
+    return static_cast<SkRegion*>(aRegion)->setRect(ToSkIRect(aRect));
+}
+
+extern "C" bool
+SkiaSkRegionSetRects(SkiaSkRegionRef aRegion, const SkiaIRect aRects[], int32_t aCount) {
+    SkTDArray<SkIRect> rects;
+    for (int32_t i = 0; i < aCount; i++) {
+        *rects.append() = ToSkIRect(&aRects[i]);
+    }
+    return static_cast<SkRegion*>(aRegion)->setRects(rects.begin(), rects.count());
+}
+
+extern "C" bool
+SkiaSkRegionSetPath(SkiaSkRegionRef aRegion, SkiaSkPathRef aPath, SkiaSkRegionRef aClip) {
+    return static_cast<SkRegion*>(aRegion)->setPath(*static_cast<SkPath*>(aPath),
+                                                    *static_cast<SkRegion*>(aClip));
+}
+
+extern "C" bool
+SkiaSkRegionIsEmpty(SkiaSkRegionRef aRegion) {
+    return static_cast<SkRegion*>(aRegion)->isEmpty();
+}
+
+extern "C" bool
+SkiaSkRegionIsRect(SkiaSkRegionRef aRegion) {
+    return static_cast<SkRegion*>(aRegion)->isRect();
+}
+
+extern "C" void
+SkiaSkRegionGetBounds(SkiaSkRegionRef aRegion, SkiaIRect* aBounds) {
+    FromSkIRect(static_cast<SkRegion*>(aRegion)->getBounds(), aBounds);
+}
+
+extern "C" bool
+SkiaSkRegionGetBoundaryPath(SkiaSkRegionRef aRegion, SkiaSkPathRef aResult) {
+    return static_cast<SkRegion*>(aRegion)->getBoundaryPath(static_cast<SkPath*>(aResult));
+}
+
+extern "C" bool
+SkiaSkRegionContainsPoint(SkiaSkRegionRef aRegion, int32_t x, int32_t y) {
+    return static_cast<SkRegion*>(aRegion)->contains(x, y);
+}
+
+extern "C" bool
+SkiaSkRegionContainsRect(SkiaSkRegionRef aRegion, const SkiaIRect* aRect) {
+    return static_cast<SkRegion*>(aRegion)->contains(ToSkIRect(aRect));
+}
+
+extern "C" bool
+SkiaSkRegionContainsRegion(SkiaSkRegionRef aRegion, SkiaSkRegionRef aOther) {
+    return static_cast<SkRegion*>(aRegion)->contains(*static_cast<SkRegion*>(aOther));
+}
+
+extern "C" bool
+SkiaSkRegionIntersectsRect(SkiaSkRegionRef aRegion, const SkiaIRect* aRect) {
+    return static_cast<SkRegion*>(aRegion)->intersects(ToSkIRect(aRect));
+}
+
+extern "C" bool
+SkiaSkRegionIntersectsRegion(SkiaSkRegionRef aRegion, SkiaSkRegionRef aOther) {
+    return static_cast<SkRegion*>(aRegion)->intersects(*static_cast<SkRegion*>(aOther));
+}
+
+extern "C" void
+SkiaSkRegionTranslate(SkiaSkRegionRef aRegion, int32_t dx, int32_t dy) {
+    static_cast<SkRegion*>(aRegion)->translate(dx, dy);
+}
+
+extern "C" bool
+SkiaSkRegionOpRect(SkiaSkRegionRef aRegion, const SkiaIRect* aRect, int32_t aOp) {
+    return static_cast<SkRegion*>(aRegion)->op(ToSkIRect(aRect), static_cast<SkRegion::Op>(aOp));
+}
+
+extern "C" bool
+SkiaSkRegionOpRegion(SkiaSkRegionRef aRegion, SkiaSkRegionRef aOther, int32_t aOp) {
+    return static_cast<SkRegion*>(aRegion)->op(*static_cast<SkRegion*>(aOther),
+                                               static_cast<SkRegion::Op>(aOp));
+}
+
+extern "C" SkiaSkRegionIteratorRef
+SkiaSkRegionIteratorCreate(SkiaSkRegionRef aRegion) {
+    return new SkRegion::Iterator(*static_cast<SkRegion*>(aRegion));
+}
+
+extern "C" void
+SkiaSkRegionIteratorDelete(SkiaSkRegionIteratorRef aIterator) {
+    delete static_cast<SkRegion::Iterator*>(aIterator);
+}
+
+extern "C" bool
+SkiaSkRegionIteratorNext(SkiaSkRegionIteratorRef aIterator, SkiaIRect* aRect) {
+    SkRegion::Iterator* iterator = static_cast<SkRegion::Iterator*>(aIterator);
+    if (iterator->done()) {
+        return false;
+    }
+    FromSkIRect(iterator->rect(), aRect);
+    iterator->next();
+    return true;
+}
+
+extern "C" void
+SkiaSkRRectSetRectRadii(SkiaRRect* aRRect) {
+    FromSkRRect(ToSkRRect(aRRect), aRRect);
+}
+
+extern "C" void
+SkiaSkRRectSetOval(SkiaRRect* aRRect, const SkiaRect* aOval) {
+    SkRRect rrect;
+    rrect.setOval(ToSkRect(aOval));
+    FromSkRRect(rrect, aRRect);
+}
+
+extern "C" void
+SkiaSkRRectSetRectXY(SkiaRRect* aRRect, const SkiaRect* aRect, float aXRadius, float aYRadius) {
+    SkRRect rrect;
+    rrect.setRectXY(ToSkRect(aRect), aXRadius, aYRadius);
+    FromSkRRect(rrect, aRRect);
+}
+
+extern "C" int32_t
+SkiaSkRRectGetType(const SkiaRRect* aRRect) {
+    return ToSkRRect(aRRect).getType();
+}
+
+// Negative insets outset the rrect, as SkRRect::outset() does.
+extern "C" void
+SkiaSkRRectInset(SkiaRRect* aRRect, float dx, float dy) {
+    SkRRect rrect = ToSkRRect(aRRect);
+    rrect.inset(dx, dy);
+    FromSkRRect(rrect, aRRect);
+}
+
+extern "C" bool
+SkiaSkRRectContainsRect(const SkiaRRect* aRRect, const SkiaRect* aRect) {
+    return ToSkRRect(aRRect).contains(ToSkRect(aRect));
+}
+
+// SkRRect only tests rectangles, so test points against its outline.
+extern "C" bool
+SkiaSkRRectContainsPoint(const SkiaRRect* aRRect, float x, float y) {
+    SkPath path;
+    path.addRRect(ToSkRRect(aRRect));
+    return path.contains(x, y);
+}
+
+// Only scales, translations and 90 degree rotations keep an rrect an rrect,
+// so this fails for any other matrix and leaves the rrect unchanged.
+extern "C" bool
+SkiaSkRRectTransform(SkiaRRect* aRRect, const SkiaMatrix* aMatrix) {
+    SkRRect rrect;
+    if (!ToSkRRect(aRRect).transform(ToSkMatrix(aMatrix), &rrect)) {
+        return false;
+    }
+    FromSkRRect(rrect, aRRect);
+    return true;
+}
+
+extern "C" void
+SkiaSkPathAddRRect(SkiaSkPathRef aPath, const SkiaRRect* aRRect, bool aClockwise) {
+    static_cast<SkPath*>(aPath)->addRRect(ToSkRRect(aRRect), ToSkPathDirection(aClockwise));
+}
+
+extern "C" SkiaSkPaintRef
+SkiaSkPaintCreate() {
+    return new SkPaint();
+}
+
+extern "C" SkiaSkPaintRef
+SkiaSkPaintClone(SkiaSkPaintRef aPaint) {
+    return new SkPaint(*static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" void
+SkiaSkPaintDelete(SkiaSkPaintRef aPaint) {
+    delete static_cast<SkPaint*>(aPaint);
+}
+
+extern "C" uint32_t
+SkiaSkPaintGetColor(SkiaSkPaintRef aPaint) {
+    return static_cast<SkPaint*>(aPaint)->getColor();
+}
+
+extern "C" void
+SkiaSkPaintSetColor(SkiaSkPaintRef aPaint, uint32_t aColor) {
+    static_cast<SkPaint*>(aPaint)->setColor(aColor);
+}
+
+extern "C" bool
+SkiaSkPaintIsAntiAlias(SkiaSkPaintRef aPaint) {
+    return static_cast<SkPaint*>(aPaint)->isAntiAlias();
+}
+
+extern "C" void
+SkiaSkPaintSetAntiAlias(SkiaSkPaintRef aPaint, bool aAntiAlias) {
+    static_cast<SkPaint*>(aPaint)->setAntiAlias(aAntiAlias);
+}
+
+extern "C" int32_t
+SkiaSkPaintGetStyle(SkiaSkPaintRef aPaint) {
+    return static_cast<SkPaint*>(aPaint)->getStyle();
+}
+
+extern "C" void
+SkiaSkPaintSetStyle(SkiaSkPaintRef aPaint, int32_t aStyle) {
+    static_cast<SkPaint*>(aPaint)->setStyle(static_cast<SkPaint::Style>(aStyle));
+}
+
+extern "C" void
+SkiaSkPaintSetStroke(SkiaSkPaintRef aPaint,
+                     float aWidth,
+                     int32_t aCap,
+                     int32_t aJoin,
+                     float aMiterLimit,
+                     SkiaSkPathEffectRef aPathEffect) {
+    SetStrokeParams(static_cast<SkPaint*>(aPaint), aWidth, aCap, aJoin, aMiterLimit, aPathEffect);
+}
+
+extern "C" SkiaSkSurfaceRef
+SkiaSkSurfaceCreateRaster(int32_t aWidth, int32_t aHeight) {
+    return SkSurface::NewRasterPMColor(aWidth, aHeight);
+}
+
+extern "C" SkiaSkSurfaceRef
+SkiaSkSurfaceCreateRenderTarget(SkiaGrContextRef aContext, int32_t aWidth, int32_t aHeight) {
+    return SkSurface::NewRenderTarget(static_cast<GrContext*>(aContext),
+                                      SkImageInfo::MakeN32Premul(aWidth, aHeight));
+}
+
+extern "C" void
+SkiaSkSurfaceRetain(SkiaSkSurfaceRef aSurface) {
+    SkSafeRef(static_cast<SkSurface*>(aSurface));
+}
+
+extern "C" void
+SkiaSkSurfaceRelease(SkiaSkSurfaceRef aSurface) {
+    SkSafeUnref(static_cast<SkSurface*>(aSurface));
+}
+
+extern "C" int32_t
+SkiaSkSurfaceGetWidth(SkiaSkSurfaceRef aSurface) {
+    return static_cast<SkSurface*>(aSurface)->width();
+}
+
+extern "C" int32_t
+SkiaSkSurfaceGetHeight(SkiaSkSurfaceRef aSurface) {
+    return static_cast<SkSurface*>(aSurface)->height();
+}
+
+extern "C" SkiaSkCanvasRef
+SkiaSkSurfaceGetCanvas(SkiaSkSurfaceRef aSurface) {
+    return static_cast<SkSurface*>(aSurface)->getCanvas();
+}
+
+extern "C" int32_t
+SkiaSkCanvasSave(SkiaSkCanvasRef aCanvas) {
+    return static_cast<SkCanvas*>(aCanvas)->save();
+}
+
+extern "C" void
+SkiaSkCanvasRestore(SkiaSkCanvasRef aCanvas) {
+    static_cast<SkCanvas*>(aCanvas)->restore();
+}
+
+extern "C" void
+SkiaSkCanvasRestoreToCount(SkiaSkCanvasRef aCanvas, int32_t aSaveCount) {
+    static_cast<SkCanvas*>(aCanvas)->restoreToCount(aSaveCount);
+}
+
+extern "C" void
+SkiaSkCanvasTranslate(SkiaSkCanvasRef aCanvas, float dx, float dy) {
+    static_cast<SkCanvas*>(aCanvas)->translate(dx, dy);
+}
+
+extern "C" void
+SkiaSkCanvasScale(SkiaSkCanvasRef aCanvas, float sx, float sy) {
+    static_cast<SkCanvas*>(aCanvas)->scale(sx, sy);
+}
+
+extern "C" void
+SkiaSkCanvasRotate(SkiaSkCanvasRef aCanvas, float aDegrees) {
+    static_cast<SkCanvas*>(aCanvas)->rotate(aDegrees);
+}
+
+extern "C" void
+SkiaSkCanvasConcat(SkiaSkCanvasRef aCanvas, const SkiaMatrix* aMatrix) {
+    static_cast<SkCanvas*>(aCanvas)->concat(ToSkMatrix(aMatrix));
+}
+
+extern "C" void
+SkiaSkCanvasSetMatrix(SkiaSkCanvasRef aCanvas, const SkiaMatrix* aMatrix) {
+    static_cast<SkCanvas*>(aCanvas)->setMatrix(ToSkMatrix(aMatrix));
+}
+
+extern "C" void
+SkiaSkCanvasClear(SkiaSkCanvasRef aCanvas, uint32_t aColor) {
+    static_cast<SkCanvas*>(aCanvas)->clear(aColor);
+}
+
+extern "C" void
+SkiaSkCanvasFlush(SkiaSkCanvasRef aCanvas) {
+    static_cast<SkCanvas*>(aCanvas)->flush();
+}
+
+extern "C" void
+SkiaSkCanvasClipRect(SkiaSkCanvasRef aCanvas, const SkiaRect* aRect, int32_t aOp, bool aAntiAlias) {
+    static_cast<SkCanvas*>(aCanvas)->clipRect(ToSkRect(aRect), static_cast<SkRegion::Op>(aOp), aAntiAlias);
+}
+
+extern "C" void
+SkiaSkCanvasClipRRect(SkiaSkCanvasRef aCanvas, const SkiaRRect* aRRect, int32_t aOp, bool aAntiAlias) {
+    static_cast<SkCanvas*>(aCanvas)->clipRRect(ToSkRRect(aRRect), static_cast<SkRegion::Op>(aOp), aAntiAlias);
+}
+
+extern "C" void
+SkiaSkCanvasClipPath(SkiaSkCanvasRef aCanvas, SkiaSkPathRef aPath, int32_t aOp, bool aAntiAlias) {
+    static_cast<SkCanvas*>(aCanvas)->clipPath(*static_cast<SkPath*>(aPath),
+                                              static_cast<SkRegion::Op>(aOp),
+                                              aAntiAlias);
+}
+
+extern "C" void
+SkiaSkCanvasClipRegion(SkiaSkCanvasRef aCanvas, SkiaSkRegionRef aDeviceRegion, int32_t aOp) {
+    static_cast<SkCanvas*>(aCanvas)->clipRegion(*static_cast<SkRegion*>(aDeviceRegion),
+                                                static_cast<SkRegion::Op>(aOp));
+}
+
+extern "C" void
+SkiaSkCanvasDrawPaint(SkiaSkCanvasRef aCanvas, SkiaSkPaintRef aPaint) {
+    static_cast<SkCanvas*>(aCanvas)->drawPaint(*static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" void
+SkiaSkCanvasDrawRect(SkiaSkCanvasRef aCanvas, const SkiaRect* aRect, SkiaSkPaintRef aPaint) {
+    static_cast<SkCanvas*>(aCanvas)->drawRect(ToSkRect(aRect), *static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" void
+SkiaSkCanvasDrawOval(SkiaSkCanvasRef aCanvas, const SkiaRect* aOval, SkiaSkPaintRef aPaint) {
+    static_cast<SkCanvas*>(aCanvas)->drawOval(ToSkRect(aOval), *static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" void
+SkiaSkCanvasDrawRRect(SkiaSkCanvasRef aCanvas, const SkiaRRect* aRRect, SkiaSkPaintRef aPaint) {
+    static_cast<SkCanvas*>(aCanvas)->drawRRect(ToSkRRect(aRRect), *static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" void
+SkiaSkCanvasDrawDRRect(SkiaSkCanvasRef aCanvas,
+                       const SkiaRRect* aOuter,
+                       const SkiaRRect* aInner,
+                       SkiaSkPaintRef aPaint) {
+    static_cast<SkCanvas*>(aCanvas)->drawDRRect(ToSkRRect(aOuter),
+                                                ToSkRRect(aInner),
+                                                *static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" void
+SkiaSkCanvasDrawPath(SkiaSkCanvasRef aCanvas, SkiaSkPathRef aPath, SkiaSkPaintRef aPaint) {
+    static_cast<SkCanvas*>(aCanvas)->drawPath(*static_cast<SkPath*>(aPath), *static_cast<SkPaint*>(aPaint));
+}
+
+// SkCanvas has no drawRegion() in this version of Skia, so draw the region's
+// outline instead.
+extern "C" void
+SkiaSkCanvasDrawRegion(SkiaSkCanvasRef aCanvas, SkiaSkRegionRef aRegion, SkiaSkPaintRef aPaint) {
+    SkPath path;
+    if (static_cast<SkRegion*>(aRegion)->getBoundaryPath(&path)) {
+        static_cast<SkCanvas*>(aCanvas)->drawPath(path, *static_cast<SkPaint*>(aPaint));
+    }
+}
+
+extern "C" bool
+SkiaSkMatrix44Invert(const SkiaMatrix44* aMatrix, SkiaMatrix44* aResult) {
+    SkMatrix44 inverse(SkMatrix44::kUninitialized_Constructor);
+    if (!ToSkMatrix44(aMatrix).invert(&inverse)) {
+        return false;
+    }
+    FromSkMatrix44(inverse, aResult);
+    return true;
+}
+
+extern "C" void
+SkiaSkMatrix44Concat(const SkiaMatrix44* aA, const SkiaMatrix44* aB, SkiaMatrix44* aResult) {
+    SkMatrix44 result(SkMatrix44::kUninitialized_Constructor);
+    result.setConcat(ToSkMatrix44(aA), ToSkMatrix44(aB));
+    FromSkMatrix44(result, aResult);
+}
+
+extern "C" void
+SkiaSkMatrix44MapScalars(const SkiaMatrix44* aMatrix, const float aSrc[4], float aDst[4]) {
+    ToSkMatrix44(aMatrix).mapScalars(aSrc, aDst);
+}
+
+// Drops the z row and column, which projects the matrix onto the z = 0 plane.
+extern "C" void
+SkiaSkMatrix44ToSkMatrix(const SkiaMatrix44* aMatrix, SkiaMatrix* aResult) {
+    FromSkMatrix(ToSkMatrix44(aMatrix), aResult);
+}
+
+extern "C" void
+SkiaSkMatrix44FromSkMatrix(const SkiaMatrix* aMatrix, SkiaMatrix44* aResult) {
+    FromSkMatrix44(SkMatrix44(ToSkMatrix(aMatrix)), aResult);
+}
+
+extern "C" bool
+SkiaSkMatrixInvert(const SkiaMatrix* aMatrix, SkiaMatrix* aResult) {
+    SkMatrix inverse;
+    if (!ToSkMatrix(aMatrix).invert(&inverse)) {
+        return false;
+    }
+    FromSkMatrix(inverse, aResult);
+    return true;
+}
+
+extern "C" void
+SkiaSkMatrixConcat(const SkiaMatrix* aA, const SkiaMatrix* aB, SkiaMatrix* aResult) {
+    SkMatrix result;
+    result.setConcat(ToSkMatrix(aA), ToSkMatrix(aB));
+    FromSkMatrix(result, aResult);
+}
+
+extern "C" void
+SkiaSkMatrixMapPoints(const SkiaMatrix* aMatrix, SkiaPoint aPoints[], int32_t aCount) {
+    SK_COMPILE_ASSERT(sizeof(SkiaPoint) == sizeof(SkPoint), point_size_mismatch);
+    ToSkMatrix(aMatrix).mapPoints(reinterpret_cast<SkPoint*>(aPoints), aCount);
+}
+
+extern "C" Skia3DViewRef
+Skia3DViewCreate() {
+    return new Sk3DView();
+}
+
+extern "C" void
+Skia3DViewDelete(Skia3DViewRef aView) {
+    delete static_cast<Sk3DView*>(aView);
+}
+
+extern "C" void
+Skia3DViewSave(Skia3DViewRef aView) {
+    static_cast<Sk3DView*>(aView)->save();
+}
+
+extern "C" void
+Skia3DViewRestore(Skia3DViewRef aView) {
+    static_cast<Sk3DView*>(aView)->restore();
+}
+
+extern "C" void
+Skia3DViewTranslate(Skia3DViewRef aView, float x, float y, float z) {
+    static_cast<Sk3DView*>(aView)->translate(x, y, z);
+}
+
+extern "C" void
+Skia3DViewRotateX(Skia3DViewRef aView, float aDegrees) {
+    static_cast<Sk3DView*>(aView)->rotateX(aDegrees);
+}
+
+extern "C" void
+Skia3DViewRotateY(Skia3DViewRef aView, float aDegrees) {
+    static_cast<Sk3DView*>(aView)->rotateY(aDegrees);
+}
+
+extern "C" void
+Skia3DViewRotateZ(Skia3DViewRef aView, float aDegrees) {
+    static_cast<Sk3DView*>(aView)->rotateZ(aDegrees);
+}
+
+extern "C" void
+Skia3DViewGetMatrix(Skia3DViewRef aView, SkiaMatrix* aResult) {
+    SkMatrix matrix;
+    static_cast<Sk3DView*>(aView)->getMatrix(&matrix);
+    FromSkMatrix(matrix, aResult);
+}
+
+extern "C" void
+Skia3DViewApplyToCanvas(Skia3DViewRef aView, SkiaSkCanvasRef aCanvas) {
+    static_cast<Sk3DView*>(aView)->applyToCanvas(static_cast<SkCanvas*>(aCanvas));
+}
+
+extern "C" float
+Skia3DViewDotWithNormal(Skia3DViewRef aView, float dx, float dy, float dz) {
+    return static_cast<Sk3DView*>(aView)->dotWithNormal(dx, dy, dz);
+}
+
+static void
+ToSkPoint3D(const SkiaPoint3D& aPoint, SkPoint3D* aResult) {
+    aResult->set(aPoint.x, aPoint.y, aPoint.z);
+}
+
+extern "C" void
+SkiaSkCamera3DPatchToMatrix(const SkiaCamera3D* aCamera, const SkiaPatch3D* aPatch, SkiaMatrix* aResult) {
+    SkCamera3D camera;
+    ToSkPoint3D(aCamera->location, &camera.fLocation);
+    ToSkPoint3D(aCamera->axis, &camera.fAxis);
+    ToSkPoint3D(aCamera->zenith, &camera.fZenith);
+    ToSkPoint3D(aCamera->observer, &camera.fObserver);
+    camera.update();
+
+    SkPatch3D patch;
+    ToSkPoint3D(aPatch->u, &patch.fU);
+    ToSkPoint3D(aPatch->v, &patch.fV);
+    ToSkPoint3D(aPatch->origin, &patch.fOrigin);
+
+    SkMatrix matrix;
+    camera.patchToMatrix(patch, &matrix);
+    FromSkMatrix(matrix, aResult);
+}
+
+#ifdef SKIA_C_ENABLE_GIF
+// The movie factory that SkMovie_gif.cpp registers. It has external linkage
+// but is not declared in any header.
+extern SkMovie* Factory(SkStreamRewindable*);
+#endif
+
+// Decoders register themselves from static initializers, which the linker
+// drops from a static library unless something references their objects.
+static int
+ForceImageDecoderLinking(bool aDoNotPassTrue) {
+    if (aDoNotPassTrue) {
+        CreateBMPImageDecoder();
+        CreateICOImageDecoder();
+        CreateKTXImageDecoder();
+        CreatePKMImageDecoder();
+        CreateWBMPImageDecoder();
+#ifdef SKIA_C_ENABLE_GIF
+        CreateGIFImageDecoder();
+        Factory(NULL);
+#endif
+#ifdef SKIA_C_ENABLE_JPEG
+        CreateJPEGImageDecoder();
+#endif
+#ifdef SKIA_C_ENABLE_PNG
+        CreatePNGImageDecoder();
+#endif
+#ifdef SKIA_C_ENABLE_WEBP
+        CreateWEBPImageDecoder();
+#endif
+        return -1;
+    }
+    return 0;
+}
+
+static int sImageDecoderLinkingForced = ForceImageDecoderLinking(false);
+
+extern "C" SkiaSkImageRef
+SkiaSkImageCreateFromEncoded(const void* aData, size_t aLength, int32_t* aFormat) {
+    SkMemoryStream stream(aData, aLength, false);
+    *aFormat = SkImageDecoder::GetStreamFormat(&stream);
+    if (*aFormat == SkImageDecoder::kUnknown_Format) {
+        return NULL;
+    }
+
+    SkBitmap bitmap;
+    if (!SkImageDecoder::DecodeMemory(aData, aLength, &bitmap, kUnknown_SkColorType,
+                                      SkImageDecoder::kDecodePixels_Mode)) {
+        return NULL;
+    }
+    // Nothing else holds the decoded pixels, so the image can adopt them.
+    return SkNewImageFromBitmap(bitmap, true);
+}
+
+extern "C" void
+SkiaSkImageRetain(SkiaSkImageRef aImage) {
+    SkSafeRef(static_cast<SkImage*>(aImage));
+}
+
+extern "C" void
+SkiaSkImageRelease(SkiaSkImageRef aImage) {
+    SkSafeUnref(static_cast<SkImage*>(aImage));
+}
+
+extern "C" int32_t
+SkiaSkImageGetWidth(SkiaSkImageRef aImage) {
+    return static_cast<SkImage*>(aImage)->width();
+}
+
+extern "C" int32_t
+SkiaSkImageGetHeight(SkiaSkImageRef aImage) {
+    return static_cast<SkImage*>(aImage)->height();
+}
+
+extern "C" void
+SkiaSkCanvasDrawImage(SkiaSkCanvasRef aCanvas, SkiaSkImageRef aImage, float aX, float aY,
+                      SkiaSkPaintRef aPaint) {
+    static_cast<SkImage*>(aImage)->draw(static_cast<SkCanvas*>(aCanvas), aX, aY,
+                                        static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" SkiaSkImageRef
+SkiaSkSurfaceNewImageSnapshot(SkiaSkSurfaceRef aSurface) {
+    return static_cast<SkSurface*>(aSurface)->newImageSnapshot();
+}
+
+extern "C" bool
+SkiaSkImageEncode(SkiaSkImageRef aImage, int32_t aType, int32_t aQuality,
+                  SkiaWriteCallback aCallback, void* aClosure) {
+    SkImage_Base* image = static_cast<SkImage_Base*>(static_cast<SkImage*>(aImage));
+    SkBitmap bitmap;
+    if (!image->getROPixels(&bitmap)) {
+        return false;
+    }
+    SkiaCallbackWStream stream(aCallback, aClosure);
+    return SkImageEncoder::EncodeStream(&stream, bitmap,
+                                        static_cast<SkImageEncoder::Type>(aType), aQuality);
+}
+
+extern "C" SkiaSkMovieRef
+SkiaSkMovieCreateFromEncoded(const void* aData, size_t aLength) {
+    return SkMovie::DecodeMemory(aData, aLength);
+}
+
+extern "C" void
+SkiaSkMovieRelease(SkiaSkMovieRef aMovie) {
+    SkSafeUnref(static_cast<SkMovie*>(aMovie));
+}
+
+extern "C" uint32_t
+SkiaSkMovieGetDuration(SkiaSkMovieRef aMovie) {
+    return static_cast<SkMovie*>(aMovie)->duration();
+}
+
+extern "C" int32_t
+SkiaSkMovieGetWidth(SkiaSkMovieRef aMovie) {
+    return static_cast<SkMovie*>(aMovie)->width();
+}
+
+extern "C" int32_t
+SkiaSkMovieGetHeight(SkiaSkMovieRef aMovie) {
+    return static_cast<SkMovie*>(aMovie)->height();
+}
+
+extern "C" bool
+SkiaSkMovieIsOpaque(SkiaSkMovieRef aMovie) {
+    return static_cast<SkMovie*>(aMovie)->isOpaque();
+}
+
+extern "C" bool
+SkiaSkMovieSetTime(SkiaSkMovieRef aMovie, uint32_t aTime) {
+    return static_cast<SkMovie*>(aMovie)->setTime(aTime);
+}
+
+extern "C" SkiaSkImageRef
+SkiaSkMovieGetFrame(SkiaSkMovieRef aMovie) {
+    const SkBitmap& bitmap = static_cast<SkMovie*>(aMovie)->bitmap();
+    if (bitmap.isNull()) {
+        return NULL;
+    }
+    // The movie reuses its bitmap for later frames, so copy the pixels.
+    return SkNewImageFromBitmap(bitmap, false);
+}
+
+extern "C" SkiaSkImageRef
+SkiaSkImageCreateLazyFromEncoded(const void* aData, size_t aLength, int32_t* aFormat) {
+    SkMemoryStream stream(aData, aLength, false);
+    *aFormat = SkImageDecoder::GetStreamFormat(&stream);
+    if (*aFormat == SkImageDecoder::kUnknown_Format) {
+        return NULL;
+    }
+
+    // The generator keeps a copy of the encoded data, and the pixel ref
+    // decodes it into the global discardable memory pool whenever the pixels
+    // are locked after having been purged.
+    SkAutoDataUnref data(SkData::NewWithCopy(aData, aLength));
+    SkImageGenerator* generator =
+        SkDecodingImageGenerator::Create(data, SkDecodingImageGenerator::Options());
+    SkBitmap bitmap;
+    if (!SkInstallDiscardablePixelRef(generator, &bitmap)) {
+        return NULL;
+    }
+    bitmap.setImmutable();
+    return SkNewImageFromBitmap(bitmap, true);
+}
+
+extern "C" size_t
+SkiaSkDiscardableMemoryPoolGetRAMUsed() {
+    return SkGetGlobalDiscardableMemoryPool()->getRAMUsed();
+}
+
+extern "C" size_t
+SkiaSkDiscardableMemoryPoolGetRAMBudget() {
+    return SkGetGlobalDiscardableMemoryPool()->getRAMBudget();
+}
+
+extern "C" void
+SkiaSkDiscardableMemoryPoolSetRAMBudget(size_t aBudget) {
+    SkGetGlobalDiscardableMemoryPool()->setRAMBudget(aBudget);
+}
+
+extern "C" void
+SkiaSkDiscardableMemoryPoolPurge() {
+    SkGetGlobalDiscardableMemoryPool()->dumpPool();
+}
+
+extern "C" SkiaSkImageRef
+SkiaSkImageCreateFromGenerator(const SkiaImageGeneratorCallbacks* aCallbacks, void* aClosure) {
+    // The pixel ref takes ownership of the generator, even on failure.
+    SkBitmap bitmap;
+    if (!SkInstallDiscardablePixelRef(SkNEW_ARGS(SkiaImageGenerator, (aCallbacks, aClosure)),
+                                      &bitmap)) {
+        return NULL;
+    }
+    bitmap.setImmutable();
+    return SkNewImageFromBitmap(bitmap, true);
+}
+
+extern "C" int32_t
+SkiaSkPaintGetFilterLevel(SkiaSkPaintRef aPaint) {
+    return static_cast<SkPaint*>(aPaint)->getFilterLevel();
+}
+
+extern "C" void
+SkiaSkPaintSetFilterLevel(SkiaSkPaintRef aPaint, int32_t aLevel) {
+    static_cast<SkPaint*>(aPaint)->setFilterLevel(static_cast<SkPaint::FilterLevel>(aLevel));
+}
+
+extern "C" void
+SkiaSkCanvasDrawImageRect(SkiaSkCanvasRef aCanvas,
+                          SkiaSkImageRef aImage,
+                          const SkiaRect* aSrc,
+                          const SkiaRect* aDst,
+                          SkiaSkPaintRef aPaint) {
+    SkRect src;
+    if (aSrc) {
+        src = ToSkRect(aSrc);
+    }
+    static_cast<SkImage*>(aImage)->draw(static_cast<SkCanvas*>(aCanvas),
+                                        aSrc ? &src : NULL,
+                                        ToSkRect(aDst),
+                                        static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" bool
+SkiaSkBitmapScalerResize(const SkiaImageInfo* aSrcInfo,
+                         const void* aSrcPixels,
+                         size_t aSrcRowBytes,
+                         int32_t aMethod,
+                         int32_t aDstWidth,
+                         int32_t aDstHeight,
+                         void* aDstPixels,
+                         size_t aDstRowBytes) {
+    SkBitmap src;
+    if (!src.installPixels(ToSkImageInfo(aSrcInfo), const_cast<void*>(aSrcPixels),
+                           aSrcRowBytes)) {
+        return false;
+    }
+
+    // Use the same SIMD convolution procs as SkBitmapProcState's own
+    // high quality scaling.
+    SkConvolutionProcs procs;
+    sk_bzero(&procs, sizeof(procs));
+    SkBitmapProcState().platformConvolutionProcs(&procs);
+
+    SkBitmap result;
+    if (!SkBitmapScaler::Resize(&result, src, static_cast<SkBitmapScaler::ResizeMethod>(aMethod),
+                                aDstWidth, aDstHeight, procs)) {
+        return false;
+    }
+    SkAutoLockPixels lock(result);
+    size_t rowLength = result.info().minRowBytes();
+    for (int y = 0; y < result.height(); y++) {
+        memcpy(static_cast<char*>(aDstPixels) + y * aDstRowBytes,
+               result.getAddr(0, y),
+               rowLength);
+    }
+    return true;
+}
+
+extern "C" SkiaSkImageRef
+SkiaSkImageCreateFromCompressedTexture(SkiaGrContextRef aContext,
+                                       int32_t aFormat,
+                                       int32_t aWidth,
+                                       int32_t aHeight,
+                                       const void* aData,
+                                       size_t aLength) {
+    GrContext* context = static_cast<GrContext*>(aContext);
+    GrPixelConfig config = aFormat == 0 ? kETC1_GrPixelConfig : kLATC_GrPixelConfig;
+    // Both formats are made of 4x4 blocks, and GrGpu expects whole blocks.
+    if (aWidth <= 0 || aHeight <= 0 || (aWidth & 3) || (aHeight & 3) ||
+        aLength < GrCompressedFormatDataSize(config, aWidth, aHeight) ||
+        !context->getGpu()->caps()->isConfigTexturable(config)) {
+        return NULL;
+    }
+
+    GrTextureDesc desc;
+    desc.fWidth = aWidth;
+    desc.fHeight = aHeight;
+    desc.fConfig = config;
+    SkAutoTUnref<GrTexture> texture(
+        context->createUncachedTexture(desc, const_cast<void*>(aData), 0));
+    if (!texture) {
+        return NULL;
+    }
+
+    SkImageInfo info = config == kETC1_GrPixelConfig
+                       ? SkImageInfo::MakeN32(aWidth, aHeight, kOpaque_SkAlphaType)
+                       : SkImageInfo::MakeA8(aWidth, aHeight);
+    SkBitmap bitmap;
+    bitmap.setInfo(info);
+    bitmap.setPixelRef(SkNEW_ARGS(SkGrPixelRef, (info, texture)))->unref();
+    return SkImage::NewTexture(bitmap);
+}
+
+extern "C" bool
+SkiaETC1ContainerGetData(const void* aData,
+                         size_t aLength,
+                         int32_t* aWidth,
+                         int32_t* aHeight,
+                         size_t* aOffset) {
+    const etc1_byte* bytes = static_cast<const etc1_byte*>(aData);
+    if (aLength >= ETC_PKM_HEADER_SIZE && etc1_pkm_is_valid(bytes)) {
+        *aWidth = etc1_pkm_get_width(bytes);
+        *aHeight = etc1_pkm_get_height(bytes);
+        *aOffset = ETC_PKM_HEADER_SIZE;
+        return aLength - ETC_PKM_HEADER_SIZE >= etc1_get_encoded_data_size(*aWidth, *aHeight);
+    }
+
+    // SkKTXFile checks the lengths of everything it reads.
+    SkAutoDataUnref data(SkData::NewWithCopy(aData, aLength));
+    SkKTXFile ktx(data);
+    if (!ktx.valid() || !ktx.isETC1()) {
+        return false;
+    }
+    *aWidth = ktx.width();
+    *aHeight = ktx.height();
+    *aOffset = ktx.pixelData() - data->bytes();
+    return true;
+}
+
+extern "C" bool
+SkiaETC1EncodeImage(const SkiaImageInfo* aInfo,
+                    const void* aPixels,
+                    size_t aRowBytes,
+                    void* aDst,
+                    size_t aDstLength) {
+    SkImageInfo info = ToSkImageInfo(aInfo);
+    if (info.isEmpty() ||
+        aDstLength < etc1_get_encoded_data_size(info.fWidth, info.fHeight)) {
+        return false;
+    }
+    const etc1_byte* src = static_cast<const etc1_byte*>(aPixels);
+    etc1_byte* dst = static_cast<etc1_byte*>(aDst);
+
+    // etc1 reads GL_UNSIGNED_SHORT_5_6_5 pixels, which match Skia's RGB565
+    // layout, or packed RGB bytes.
+    if (info.fColorType == kRGB_565_SkColorType) {
+        return !etc1_encode_image(src, info.fWidth, info.fHeight, 2, aRowBytes, dst);
+    }
+    if (info.fColorType != kN32_SkColorType) {
+        return false;
+    }
+    size_t rgbRowBytes = info.fWidth * 3;
+    SkAutoTMalloc<etc1_byte> rgb(rgbRowBytes * info.fHeight);
+    for (int y = 0; y < info.fHeight; y++) {
+        const SkPMColor* row = reinterpret_cast<const SkPMColor*>(src + y * aRowBytes);
+        etc1_byte* rgbRow = rgb.get() + y * rgbRowBytes;
+        for (int x = 0; x < info.fWidth; x++) {
+            SkColor color = info.fAlphaType == kUnpremul_SkAlphaType
+                            ? SkColorSetARGB(SkGetPackedA32(row[x]), SkGetPackedR32(row[x]),
+                                             SkGetPackedG32(row[x]), SkGetPackedB32(row[x]))
+                            : SkUnPreMultiply::PMColorToColor(row[x]);
+            rgbRow[x * 3] = SkColorGetR(color);
+            rgbRow[x * 3 + 1] = SkColorGetG(color);
+            rgbRow[x * 3 + 2] = SkColorGetB(color);
+        }
+    }
+    return !etc1_encode_image(rgb.get(), info.fWidth, info.fHeight, 3, rgbRowBytes, dst);
+}
+
+extern "C" bool
+SkiaSkTextureCompressorCompressLATC(const SkiaImageInfo* aInfo,
+                                    const void* aPixels,
+                                    size_t aRowBytes,
+                                    void* aDst,
+                                    size_t aDstLength) {
+    SkBitmap bitmap;
+    if (!bitmap.installPixels(ToSkImageInfo(aInfo), const_cast<void*>(aPixels), aRowBytes)) {
+        return false;
+    }
+    SkAutoDataUnref data(SkTextureCompressor::CompressBitmapToFormat(
+        bitmap, SkTextureCompressor::kLATC_Format));
+    if (!data || data->size() > aDstLength) {
+        return false;
+    }
+    memcpy(aDst, data->data(), data->size());
+    return true;
+}
+
+// Premultiplies a palette of up to 256 colours into a full table. Skia does
+// not range-check indices, so the table is padded with transparent black to
+// cover every byte value. Returns NULL if the palette is empty or too long.
+static SkColorTable*
+CreatePaletteTable(const uint32_t* aPalette, int32_t aCount, SkAlphaType* aAlphaType) {
+    if (!aPalette || aCount <= 0 || aCount > 256) {
+        return NULL;
+    }
+    SkPMColor colors[256];
+    bool opaque = true;
+    for (int i = 0; i < 256; i++) {
+        SkColor color = i < aCount ? aPalette[i] : SK_ColorTRANSPARENT;
+        colors[i] = SkPreMultiplyColor(color);
+        opaque &= i >= aCount || SkColorGetA(color) == 0xff;
+    }
+    *aAlphaType = opaque && aCount == 256 ? kOpaque_SkAlphaType : kPremul_SkAlphaType;
+    return SkNEW_ARGS(SkColorTable, (colors, 256, *aAlphaType));
+}
+
+extern "C" bool
+SkiaConvertPixels(const SkiaImageInfo* aSrcInfo,
+                  const void* aSrcPixels,
+                  size_t aSrcRowBytes,
+                  const uint32_t* aSrcPalette,
+                  int32_t aSrcPaletteCount,
+                  const SkiaImageInfo* aDstInfo,
+                  void* aDstPixels,
+                  size_t aDstRowBytes) {
+    SkImageInfo srcInfo = ToSkImageInfo(aSrcInfo);
+    SkImageInfo dstInfo = ToSkImageInfo(aDstInfo);
+    if (srcInfo.isEmpty() || srcInfo.dimensions() != dstInfo.dimensions() ||
+        dstInfo.fColorType == kIndex_8_SkColorType) {
+        return false;
+    }
+    SkAutoTUnref<SkColorTable> table;
+    if (srcInfo.fColorType == kIndex_8_SkColorType) {
+        table.reset(CreatePaletteTable(aSrcPalette, aSrcPaletteCount, &srcInfo.fAlphaType));
+        if (!table) {
+            return false;
+        }
+    }
+    if (Is32BitColorType(srcInfo.fColorType) && Is32BitColorType(dstInfo.fColorType)) {
+        return Convert32BitPixels(srcInfo, aSrcPixels, aSrcRowBytes,
+                                  dstInfo, aDstPixels, aDstRowBytes);
+    }
+
+    // Everything else goes through SkBitmap::copyTo, which only understands
+    // premultiplied pixels in the native 32-bit layout.
+    SkBitmap src;
+    if (Is32BitColorType(srcInfo.fColorType) &&
+        (srcInfo.fColorType != kN32_SkColorType || srcInfo.fAlphaType == kUnpremul_SkAlphaType)) {
+        SkAlphaType alphaType = srcInfo.fAlphaType == kUnpremul_SkAlphaType
+                                ? kPremul_SkAlphaType : srcInfo.fAlphaType;
+        if (!src.allocPixels(SkImageInfo::MakeN32(srcInfo.fWidth, srcInfo.fHeight, alphaType)) ||
+            !Convert32BitPixels(srcInfo, aSrcPixels, aSrcRowBytes,
+                                src.info(), src.getPixels(), src.rowBytes())) {
+            return false;
+        }
+    } else if (!src.installPixels(srcInfo, const_cast<void*>(aSrcPixels), aSrcRowBytes,
+                                  table, NULL, NULL)) {
+        return false;
+    }
+
+    // 4444 can only be produced from N32, and 32-bit destinations may need
+    // a second pass to swap or unpremultiply.
+    if (Is32BitColorType(dstInfo.fColorType) ||
+        (dstInfo.fColorType == kARGB_4444_SkColorType && src.colorType() != kN32_SkColorType)) {
+        SkBitmap n32;
+        if (!src.copyTo(&n32, kN32_SkColorType)) {
+            return false;
+        }
+        src.swap(n32);
+    }
+    if (Is32BitColorType(dstInfo.fColorType)) {
+        return Convert32BitPixels(src.info(), src.getPixels(), src.rowBytes(),
+                                  dstInfo, aDstPixels, aDstRowBytes);
+    }
+    if (dstInfo.fAlphaType == kUnpremul_SkAlphaType) {
+        return false;
+    }
+
+    SkBitmap dst;
+    if (!src.copyTo(&dst, dstInfo.fColorType)) {
+        return false;
+    }
+    SkAutoLockPixels lock(dst);
+    const char* srcRow = static_cast<const char*>(dst.getPixels());
+    char* dstRow = static_cast<char*>(aDstPixels);
+    for (int y = 0; y < dst.height(); y++) {
+        memcpy(dstRow, srcRow, dstInfo.minRowBytes());
+        srcRow += dst.rowBytes();
+        dstRow += aDstRowBytes;
+    }
+    return true;
+}
+
+extern "C" SkiaSkDataRef
+SkiaSkDataCreateWithCopy(const void* aData, size_t aLength) {
+    return SkData::NewWithCopy(aData, aLength);
+}
+
+extern "C" void
+SkiaSkDataRelease(SkiaSkDataRef aData) {
+    SkSafeUnref(static_cast<SkData*>(aData));
+}
+
+extern "C" SkiaSkImageRef
+SkiaSkImageCreateIndex8(int32_t aWidth,
+                        int32_t aHeight,
+                        SkiaSkDataRef aIndices,
+                        size_t aRowBytes,
+                        const uint32_t* aPalette,
+                        int32_t aCount) {
+    SkData* indices = static_cast<SkData*>(aIndices);
+    if (aWidth <= 0 || aHeight <= 0 ||
+        aRowBytes < static_cast<size_t>(aWidth) ||
+        indices->size() < aRowBytes * (aHeight - 1) + aWidth) {
+        return NULL;
+    }
+
+    SkAlphaType alphaType;
+    SkAutoTUnref<SkColorTable> table(CreatePaletteTable(aPalette, aCount, &alphaType));
+    if (!table) {
+        return NULL;
+    }
+
+    SkImageInfo info = SkImageInfo::Make(aWidth, aHeight, kIndex_8_SkColorType, alphaType);
+    SkAutoTUnref<SkPixelRef> pixelRef(
+        SkMallocPixelRef::NewWithData(info, aRowBytes, table, indices));
+    if (!pixelRef) {
+        return NULL;
+    }
+    return SkNewImageFromPixelRef(info, pixelRef, aRowBytes);
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkGradientShaderCreateLinear(const SkiaPoint* aStart,
+                                 const SkiaPoint* aEnd,
+                                 const SkiaGradient* aGradient) {
+    SkPoint points[2] = { ToSkPoint(aStart), ToSkPoint(aEnd) };
+    SkiaLocalMatrix localMatrix(aGradient->localMatrix);
+    return SkGradientShader::CreateLinear(points,
+                                          aGradient->colors,
+                                          aGradient->positions,
+                                          aGradient->count,
+                                          static_cast<SkShader::TileMode>(aGradient->tileMode),
+                                          aGradient->flags,
+                                          localMatrix.get());
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkGradientShaderCreateRadial(const SkiaPoint* aCenter,
+                                 float aRadius,
+                                 const SkiaGradient* aGradient) {
+    SkiaLocalMatrix localMatrix(aGradient->localMatrix);
+    return SkGradientShader::CreateRadial(ToSkPoint(aCenter),
+                                          aRadius,
+                                          aGradient->colors,
+                                          aGradient->positions,
+                                          aGradient->count,
+                                          static_cast<SkShader::TileMode>(aGradient->tileMode),
+                                          aGradient->flags,
+                                          localMatrix.get());
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkGradientShaderCreateTwoPointRadial(const SkiaPoint* aStart,
+                                         float aStartRadius,
+                                         const SkiaPoint* aEnd,
+                                         float aEndRadius,
+                                         const SkiaGradient* aGradient) {
+    // SkTwoPointRadialGradient divides by the difference of the radii.
+    if (aStartRadius == aEndRadius) {
+        return NULL;
+    }
+    SkiaLocalMatrix localMatrix(aGradient->localMatrix);
+    return SkGradientShader::CreateTwoPointRadial(ToSkPoint(aStart),
+                                                  aStartRadius,
+                                                  ToSkPoint(aEnd),
+                                                  aEndRadius,
+                                                  aGradient->colors,
+                                                  aGradient->positions,
+                                                  aGradient->count,
+                                                  static_cast<SkShader::TileMode>(aGradient->tileMode),
+                                                  aGradient->flags,
+                                                  localMatrix.get());
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkGradientShaderCreateTwoPointConical(const SkiaPoint* aStart,
+                                          float aStartRadius,
+                                          const SkiaPoint* aEnd,
+                                          float aEndRadius,
+                                          const SkiaGradient* aGradient) {
+    SkiaLocalMatrix localMatrix(aGradient->localMatrix);
+    return SkGradientShader::CreateTwoPointConical(ToSkPoint(aStart),
+                                                   aStartRadius,
+                                                   ToSkPoint(aEnd),
+                                                   aEndRadius,
+                                                   aGradient->colors,
+                                                   aGradient->positions,
+                                                   aGradient->count,
+                                                   static_cast<SkShader::TileMode>(aGradient->tileMode),
+                                                   aGradient->flags,
+                                                   localMatrix.get());
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkGradientShaderCreateSweep(const SkiaPoint* aCenter, const SkiaGradient* aGradient) {
+    SkiaLocalMatrix localMatrix(aGradient->localMatrix);
+    return SkGradientShader::CreateSweep(aCenter->x,
+                                         aCenter->y,
+                                         aGradient->colors,
+                                         aGradient->positions,
+                                         aGradient->count,
+                                         aGradient->flags,
+                                         localMatrix.get());
+}
+
+extern "C" void
+SkiaSkShaderRetain(SkiaSkShaderRef aShader) {
+    SkSafeRef(static_cast<SkShader*>(aShader));
+}
+
+extern "C" void
+SkiaSkShaderRelease(SkiaSkShaderRef aShader) {
+    SkSafeUnref(static_cast<SkShader*>(aShader));
+}
+
+extern "C" void
+SkiaSkPaintSetShader(SkiaSkPaintRef aPaint, SkiaSkShaderRef aShader) {
+    static_cast<SkPaint*>(aPaint)->setShader(static_cast<SkShader*>(aShader));
+}
+
+extern "C" SkiaSkColorFilterRef
+SkiaSkColorFilterCreateMatrix(const float aMatrix[20]) {
+    return SkColorMatrixFilter::Create(aMatrix);
+}
+
+extern "C" void
+SkiaSkColorFilterRetain(SkiaSkColorFilterRef aColorFilter) {
+    SkSafeRef(static_cast<SkColorFilter*>(aColorFilter));
+}
+
+extern "C" void
+SkiaSkColorFilterRelease(SkiaSkColorFilterRef aColorFilter) {
+    SkSafeUnref(static_cast<SkColorFilter*>(aColorFilter));
+}
+
+extern "C" SkiaSkColorFilterRef
+SkiaSkColorFilterCreateTable(const uint8_t aTableA[256],
+                             const uint8_t aTableR[256],
+                             const uint8_t aTableG[256],
+                             const uint8_t aTableB[256]) {
+    return SkTableColorFilter::CreateARGB(aTableA, aTableR, aTableG, aTableB);
+}
+
+extern "C" SkiaSkColorFilterRef
+SkiaSkColorFilterCreateLuma() {
+    return SkLumaColorFilter::Create();
+}
+
+extern "C" SkiaSkColorFilterRef
+SkiaSkColorFilterCreateMode(uint32_t aColor, int32_t aMode) {
+    if (!IsValidXfermode(aMode)) {
+        return NULL;
+    }
+    return SkColorFilter::CreateModeFilter(aColor, static_cast<SkXfermode::Mode>(aMode));
+}
+
+extern "C" SkiaSkColorFilterRef
+SkiaSkColorFilterCreateLighting(uint32_t aMul, uint32_t aAdd) {
+    return SkColorFilter::CreateLightingFilter(aMul, aAdd);
+}
+
+extern "C" SkiaSkColorFilterRef
+SkiaSkColorFilterCompose(SkiaSkColorFilterRef aOuter, SkiaSkColorFilterRef aInner) {
+    // This Skia has no general compose filter, and the GPU backend can only
+    // take one effect per filter, so only matrices are folded together.
+    // Applying them in turn clamps in between, so the inner matrix must not
+    // need it, as in SkColorFilterImageFilter::Create.
+    SkColorMatrix outer, inner;
+    if (!static_cast<SkColorFilter*>(aOuter)->asColorMatrix(outer.fMat) ||
+        !static_cast<SkColorFilter*>(aInner)->asColorMatrix(inner.fMat)) {
+        return NULL;
+    }
+    for (int row = 0; row < 20; row += 5) {
+        float min = inner.fMat[row + 4] / 255;
+        float max = min;
+        for (int i = 0; i < 4; i++) {
+            (inner.fMat[row + i] > 0 ? max : min) += inner.fMat[row + i];
+        }
+        if (min < 0 || max > 1) {
+            return NULL;
+        }
+    }
+    SkColorMatrix matrix;
+    matrix.setConcat(outer, inner);
+    return SkColorMatrixFilter::Create(matrix);
+}
+
+extern "C" void
+SkiaSkPaintSetColorFilter(SkiaSkPaintRef aPaint, SkiaSkColorFilterRef aColorFilter) {
+    static_cast<SkPaint*>(aPaint)->setColorFilter(static_cast<SkColorFilter*>(aColorFilter));
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateSourceAlpha() {
+    // Keeps the alpha channel and turns every colour black, as SVG's
+    // SourceAlpha does.
+    static const float kAlphaOnly[20] = {
+        0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
+        0, 0, 0, 1, 0,
+    };
+    SkAutoTUnref<SkColorFilter> colorFilter(SkColorMatrixFilter::Create(kAlphaOnly));
+    return SkColorFilterImageFilter::Create(colorFilter);
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateBlur(float aSigmaX,
+                            float aSigmaY,
+                            SkiaSkImageFilterRef aInput,
+                            const SkiaCropRect* aCropRect) {
+    if (!(aSigmaX >= 0 && aSigmaY >= 0)) {
+        return NULL;
+    }
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkBlurImageFilter::Create(aSigmaX, aSigmaY,
+                                     static_cast<SkImageFilter*>(aInput), cropRect.get());
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateDropShadow(float aDx,
+                                  float aDy,
+                                  float aSigmaX,
+                                  float aSigmaY,
+                                  uint32_t aColor,
+                                  SkiaSkImageFilterRef aInput,
+                                  const SkiaCropRect* aCropRect) {
+    if (!(aSigmaX >= 0 && aSigmaY >= 0)) {
+        return NULL;
+    }
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkDropShadowImageFilter::Create(aDx, aDy, aSigmaX, aSigmaY, aColor,
+                                           static_cast<SkImageFilter*>(aInput), cropRect.get());
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateMorphology(bool aDilate,
+                                  int32_t aRadiusX,
+                                  int32_t aRadiusY,
+                                  SkiaSkImageFilterRef aInput,
+                                  const SkiaCropRect* aCropRect) {
+    if (aRadiusX < 0 || aRadiusY < 0) {
+        return NULL;
+    }
+    SkImageFilter* input = static_cast<SkImageFilter*>(aInput);
+    SkiaOptionalCropRect cropRect(aCropRect);
+    if (aDilate) {
+        return SkDilateImageFilter::Create(aRadiusX, aRadiusY, input, cropRect.get());
+    }
+    return SkErodeImageFilter::Create(aRadiusX, aRadiusY, input, cropRect.get());
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateDisplacementMap(int32_t aXChannel,
+                                       int32_t aYChannel,
+                                       float aScale,
+                                       SkiaSkImageFilterRef aDisplacement,
+                                       SkiaSkImageFilterRef aColor,
+                                       const SkiaCropRect* aCropRect) {
+    if (aXChannel < SkDisplacementMapEffect::kR_ChannelSelectorType ||
+        aXChannel > SkDisplacementMapEffect::kA_ChannelSelectorType ||
+        aYChannel < SkDisplacementMapEffect::kR_ChannelSelectorType ||
+        aYChannel > SkDisplacementMapEffect::kA_ChannelSelectorType) {
+        return NULL;
+    }
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkDisplacementMapEffect::Create(
+        static_cast<SkDisplacementMapEffect::ChannelSelectorType>(aXChannel),
+        static_cast<SkDisplacementMapEffect::ChannelSelectorType>(aYChannel),
+        aScale,
+        static_cast<SkImageFilter*>(aDisplacement),
+        static_cast<SkImageFilter*>(aColor),
+        cropRect.get());
+}
+
+static SkPoint3
+ToSkPoint3(const SkiaPoint3D& aPoint) {
+    return SkPoint3(aPoint.x, aPoint.y, aPoint.z);
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateDiffuseLighting(const SkiaLight* aLight,
+                                       float aSurfaceScale,
+                                       float aKd,
+                                       SkiaSkImageFilterRef aInput,
+                                       const SkiaCropRect* aCropRect) {
+    SkImageFilter* input = static_cast<SkImageFilter*>(aInput);
+    SkiaOptionalCropRect cropRect(aCropRect);
+    switch (aLight->type) {
+    case 0:
+        return SkLightingImageFilter::CreateDistantLitDiffuse(
+            ToSkPoint3(aLight->direction), aLight->color, aSurfaceScale, aKd,
+            input, cropRect.get());
+    case 1:
+        return SkLightingImageFilter::CreatePointLitDiffuse(
+            ToSkPoint3(aLight->location), aLight->color, aSurfaceScale, aKd,
+            input, cropRect.get());
+    case 2:
+        return SkLightingImageFilter::CreateSpotLitDiffuse(
+            ToSkPoint3(aLight->location), ToSkPoint3(aLight->target),
+            aLight->specularExponent, aLight->cutoffAngle, aLight->color, aSurfaceScale, aKd,
+            input, cropRect.get());
+    default:
+        return NULL;
+    }
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateSpecularLighting(const SkiaLight* aLight,
+                                        float aSurfaceScale,
+                                        float aKs,
+                                        float aShininess,
+                                        SkiaSkImageFilterRef aInput,
+                                        const SkiaCropRect* aCropRect) {
+    SkImageFilter* input = static_cast<SkImageFilter*>(aInput);
+    SkiaOptionalCropRect cropRect(aCropRect);
+    switch (aLight->type) {
+    case 0:
+        return SkLightingImageFilter::CreateDistantLitSpecular(
+            ToSkPoint3(aLight->direction), aLight->color, aSurfaceScale, aKs, aShininess,
+            input, cropRect.get());
+    case 1:
+        return SkLightingImageFilter::CreatePointLitSpecular(
+            ToSkPoint3(aLight->location), aLight->color, aSurfaceScale, aKs, aShininess,
+            input, cropRect.get());
+    case 2:
+        return SkLightingImageFilter::CreateSpotLitSpecular(
+            ToSkPoint3(aLight->location), ToSkPoint3(aLight->target),
+            aLight->specularExponent, aLight->cutoffAngle, aLight->color, aSurfaceScale, aKs,
+            aShininess, input, cropRect.get());
+    default:
+        return NULL;
+    }
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateMatrixConvolution(int32_t aWidth,
+                                         int32_t aHeight,
+                                         const float aKernel[],
+                                         float aGain,
+                                         float aBias,
+                                         int32_t aOffsetX,
+                                         int32_t aOffsetY,
+                                         int32_t aTileMode,
+                                         bool aConvolveAlpha,
+                                         SkiaSkImageFilterRef aInput,
+                                         const SkiaCropRect* aCropRect) {
+    // SkMatrixConvolutionImageFilter only asserts on these.
+    if (aWidth < 1 || aHeight < 1 || aWidth > SK_MaxS32 / aHeight ||
+        aOffsetX < 0 || aOffsetX >= aWidth || aOffsetY < 0 || aOffsetY >= aHeight ||
+        aTileMode < SkMatrixConvolutionImageFilter::kClamp_TileMode ||
+        aTileMode > SkMatrixConvolutionImageFilter::kClampToBlack_TileMode) {
+        return NULL;
+    }
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkMatrixConvolutionImageFilter::Create(
+        SkISize::Make(aWidth, aHeight),
+        aKernel,
+        aGain,
+        aBias,
+        SkIPoint::Make(aOffsetX, aOffsetY),
+        static_cast<SkMatrixConvolutionImageFilter::TileMode>(aTileMode),
+        aConvolveAlpha,
+        static_cast<SkImageFilter*>(aInput),
+        cropRect.get());
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateColorFilter(SkiaSkColorFilterRef aColorFilter,
+                                   SkiaSkImageFilterRef aInput,
+                                   const SkiaCropRect* aCropRect) {
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkColorFilterImageFilter::Create(static_cast<SkColorFilter*>(aColorFilter),
+                                            static_cast<SkImageFilter*>(aInput),
+                                            cropRect.get());
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateMerge(const SkiaSkImageFilterRef aInputs[],
+                             const int32_t aModes[],
+                             int32_t aCount,
+                             const SkiaCropRect* aCropRect) {
+    if (aCount < 1) {
+        return NULL;
+    }
+    SkAutoSTArray<8, SkImageFilter*> inputs(aCount);
+    SkAutoSTArray<8, SkXfermode::Mode> modes(aCount);
+    for (int i = 0; i < aCount; i++) {
+        inputs[i] = static_cast<SkImageFilter*>(aInputs[i]);
+        if (aModes && !IsValidXfermode(aModes[i])) {
+            return NULL;
+        }
+        modes[i] = aModes ? static_cast<SkXfermode::Mode>(aModes[i]) : SkXfermode::kSrcOver_Mode;
+    }
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkMergeImageFilter::Create(inputs.get(), aCount, modes.get(), cropRect.get());
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateCompose(SkiaSkImageFilterRef aOuter, SkiaSkImageFilterRef aInner) {
+    return SkComposeImageFilter::Create(static_cast<SkImageFilter*>(aOuter),
+                                        static_cast<SkImageFilter*>(aInner));
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateOffset(float aDx,
+                              float aDy,
+                              SkiaSkImageFilterRef aInput,
+                              const SkiaCropRect* aCropRect) {
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkOffsetImageFilter::Create(aDx, aDy,
+                                       static_cast<SkImageFilter*>(aInput), cropRect.get());
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateTile(const SkiaRect* aSrc,
+                            const SkiaRect* aDst,
+                            SkiaSkImageFilterRef aInput) {
+    return SkTileImageFilter::Create(ToSkRect(aSrc), ToSkRect(aDst),
+                                     static_cast<SkImageFilter*>(aInput));
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateBlend(int32_t aMode,
+                             SkiaSkImageFilterRef aBackground,
+                             SkiaSkImageFilterRef aForeground,
+                             const SkiaCropRect* aCropRect) {
+    if (!IsValidXfermode(aMode)) {
+        return NULL;
+    }
+    // This is NULL for kSrcOver_Mode, which SkXfermodeImageFilter expects.
+    SkAutoTUnref<SkXfermode> xfermode(SkXfermode::Create(static_cast<SkXfermode::Mode>(aMode)));
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkXfermodeImageFilter::Create(xfermode,
+                                         static_cast<SkImageFilter*>(aBackground),
+                                         static_cast<SkImageFilter*>(aForeground),
+                                         cropRect.get());
+}
+
+extern "C" void
+SkiaSkImageFilterRetain(SkiaSkImageFilterRef aImageFilter) {
+    SkSafeRef(static_cast<SkImageFilter*>(aImageFilter));
+}
+
+extern "C" void
+SkiaSkImageFilterRelease(SkiaSkImageFilterRef aImageFilter) {
+    SkSafeUnref(static_cast<SkImageFilter*>(aImageFilter));
+}
+
+extern "C" void
+SkiaSkPaintSetImageFilter(SkiaSkPaintRef aPaint, SkiaSkImageFilterRef aImageFilter) {
+    static_cast<SkPaint*>(aPaint)->setImageFilter(static_cast<SkImageFilter*>(aImageFilter));
+}
+
+extern "C" int32_t
+SkiaSkCanvasSaveLayer(SkiaSkCanvasRef aCanvas, const SkiaRect* aBounds, SkiaSkPaintRef aPaint) {
+    SkRect bounds;
+    if (aBounds) {
+        bounds = ToSkRect(aBounds);
+    }
+    return static_cast<SkCanvas*>(aCanvas)->saveLayer(aBounds ? &bounds : NULL,
+                                                      static_cast<SkPaint*>(aPaint));
+}
+
+extern "C" SkiaSkMaskFilterRef
+SkiaSkMaskFilterCreateBlur(int32_t aStyle, float aSigma, uint32_t aFlags) {
+    // SkBlurMaskFilter::Create checks all of its arguments.
+    return SkBlurMaskFilter::Create(static_cast<SkBlurStyle>(aStyle), aSigma, aFlags);
+}
+
+extern "C" SkiaSkMaskFilterRef
+SkiaSkMaskFilterCreateEmboss(float aSigma,
+                             const float aDirection[3],
+                             float aAmbient,
+                             float aSpecular) {
+    // SkEmbossMaskFilter normalizes the direction and blurs without
+    // checking the sigma.
+    if (!SkScalarIsFinite(aSigma) || aSigma <= 0 ||
+        (aDirection[0] == 0 && aDirection[1] == 0 && aDirection[2] == 0)) {
+        return NULL;
+    }
+    return SkBlurMaskFilter::CreateEmboss(aSigma, aDirection, aAmbient, aSpecular);
+}
+
+extern "C" SkiaSkMaskFilterRef
+SkiaSkMaskFilterCreateTable(const uint8_t aTable[256]) {
+    return SkTableMaskFilter::Create(aTable);
+}
+
+extern "C" SkiaSkMaskFilterRef
+SkiaSkMaskFilterCreateGamma(float aGamma) {
+    return SkTableMaskFilter::CreateGamma(aGamma);
+}
+
+extern "C" SkiaSkMaskFilterRef
+SkiaSkMaskFilterCreateClip(uint8_t aMin, uint8_t aMax) {
+    return SkTableMaskFilter::CreateClip(aMin, aMax);
+}
+
+extern "C" SkiaSkMaskFilterRef
+SkiaSkMaskFilterCreateStipple() {
+    return SkStippleMaskFilter::Create();
+}
+
+extern "C" void
+SkiaSkMaskFilterRetain(SkiaSkMaskFilterRef aMaskFilter) {
+    SkSafeRef(static_cast<SkMaskFilter*>(aMaskFilter));
+}
+
+extern "C" void
+SkiaSkMaskFilterRelease(SkiaSkMaskFilterRef aMaskFilter) {
+    SkSafeUnref(static_cast<SkMaskFilter*>(aMaskFilter));
+}
+
+extern "C" void
+SkiaSkPaintSetMaskFilter(SkiaSkPaintRef aPaint, SkiaSkMaskFilterRef aMaskFilter) {
+    static_cast<SkPaint*>(aPaint)->setMaskFilter(static_cast<SkMaskFilter*>(aMaskFilter));
+}
+
+extern "C" SkiaSkXfermodeRef
+SkiaSkXfermodeCreateArithmetic(float aK1, float aK2, float aK3, float aK4, bool aEnforcePremul) {
+    if (!SkScalarIsFinite(aK1) || !SkScalarIsFinite(aK2) ||
+        !SkScalarIsFinite(aK3) || !SkScalarIsFinite(aK4)) {
+        return NULL;
+    }
+    return SkArithmeticMode::Create(aK1, aK2, aK3, aK4, aEnforcePremul);
+}
+
+extern "C" SkiaSkXfermodeRef
+SkiaSkXfermodeCreateLerp(float aScale) {
+    if (!SkScalarIsFinite(aScale)) {
+        return NULL;
+    }
+    return SkLerpXfermode::Create(aScale);
+}
+
+extern "C" SkiaSkXfermodeRef
+SkiaSkXfermodeCreateAvoid(uint32_t aColor, uint8_t aTolerance, int32_t aMode) {
+    if (aMode != SkAvoidXfermode::kAvoidColor_Mode &&
+        aMode != SkAvoidXfermode::kTargetColor_Mode) {
+        return NULL;
+    }
+    return SkAvoidXfermode::Create(aColor,
+                                   aTolerance,
+                                   static_cast<SkAvoidXfermode::Mode>(aMode));
+}
+
+extern "C" SkiaSkXfermodeRef
+SkiaSkXfermodeCreatePixelXor(uint32_t aColor) {
+    return SkPixelXorXfermode::Create(aColor);
+}
+
+extern "C" void
+SkiaSkXfermodeRetain(SkiaSkXfermodeRef aXfermode) {
+    SkSafeRef(static_cast<SkXfermode*>(aXfermode));
+}
+
+extern "C" void
+SkiaSkXfermodeRelease(SkiaSkXfermodeRef aXfermode) {
+    SkSafeUnref(static_cast<SkXfermode*>(aXfermode));
+}
+
+extern "C" bool
+SkiaSkXfermodeIsSupportedOnGpu(SkiaSkXfermodeRef aXfermode) {
+    // SkGr draws anything this rejects as kSrcOver_Mode.
+    return SkXfermode::AsNewEffectOrCoeff(static_cast<SkXfermode*>(aXfermode), NULL, NULL, NULL);
+}
+
+extern "C" void
+SkiaSkPaintSetXfermode(SkiaSkPaintRef aPaint, SkiaSkXfermodeRef aXfermode) {
+    static_cast<SkPaint*>(aPaint)->setXfermode(static_cast<SkXfermode*>(aXfermode));
+}
+
+extern "C" void
+SkiaSkPaintSetXfermodeMode(SkiaSkPaintRef aPaint, int32_t aMode) {
+    if (!IsValidXfermode(aMode)) {
+        return;
+    }
+    static_cast<SkPaint*>(aPaint)->setXfermodeMode(static_cast<SkXfermode::Mode>(aMode));
+}
+
+extern "C" SkiaSkImageFilterRef
+SkiaSkImageFilterCreateXfermode(SkiaSkXfermodeRef aXfermode,
+                                SkiaSkImageFilterRef aBackground,
+                                SkiaSkImageFilterRef aForeground,
+                                const SkiaCropRect* aCropRect) {
+    SkiaOptionalCropRect cropRect(aCropRect);
+    return SkXfermodeImageFilter::Create(static_cast<SkXfermode*>(aXfermode),
+                                         static_cast<SkImageFilter*>(aBackground),
+                                         static_cast<SkImageFilter*>(aForeground),
+                                         cropRect.get());
+}
+
+extern "C" SkiaSkLayerDrawLooperBuilderRef
+SkiaSkLayerDrawLooperBuilderCreate() {
+    return new SkLayerDrawLooper::Builder();
+}
+
+extern "C" void
+SkiaSkLayerDrawLooperBuilderDelete(SkiaSkLayerDrawLooperBuilderRef aBuilder) {
+    delete static_cast<SkLayerDrawLooper::Builder*>(aBuilder);
+}
+
+static bool
+ToLayerInfo(const SkiaLayerInfo* aInfo, SkLayerDrawLooper::LayerInfo* aResult) {
+    if (!IsValidXfermode(aInfo->colorMode) ||
+        !SkScalarIsFinite(aInfo->offset.x) || !SkScalarIsFinite(aInfo->offset.y)) {
+        return false;
+    }
+    aResult->fPaintBits = static_cast<SkLayerDrawLooper::BitFlags>(aInfo->paintBits);
+    aResult->fColorMode = static_cast<SkXfermode::Mode>(aInfo->colorMode);
+    aResult->fOffset = ToSkPoint(&aInfo->offset);
+    aResult->fPostTranslate = aInfo->postTranslate;
+    return true;
+}
+
+extern "C" bool
+SkiaSkLayerDrawLooperBuilderAddLayer(SkiaSkLayerDrawLooperBuilderRef aBuilder,
+                                     const SkiaLayerInfo* aInfo,
+                                     SkiaSkPaintRef aPaint) {
+    SkLayerDrawLooper::LayerInfo info;
+    if (!ToLayerInfo(aInfo, &info)) {
+        return false;
+    }
+    SkLayerDrawLooper::Builder* builder = static_cast<SkLayerDrawLooper::Builder*>(aBuilder);
+    *builder->addLayer(info) = *static_cast<SkPaint*>(aPaint);
+    return true;
+}
+
+extern "C" bool
+SkiaSkLayerDrawLooperBuilderAddLayerOnTop(SkiaSkLayerDrawLooperBuilderRef aBuilder,
+                                          const SkiaLayerInfo* aInfo,
+                                          SkiaSkPaintRef aPaint) {
+    SkLayerDrawLooper::LayerInfo info;
+    if (!ToLayerInfo(aInfo, &info)) {
+        return false;
+    }
+    SkLayerDrawLooper::Builder* builder = static_cast<SkLayerDrawLooper::Builder*>(aBuilder);
+    *builder->addLayerOnTop(info) = *static_cast<SkPaint*>(aPaint);
+    return true;
+}
+
+extern "C" SkiaSkDrawLooperRef
+SkiaSkLayerDrawLooperBuilderDetachLooper(SkiaSkLayerDrawLooperBuilderRef aBuilder) {
+    return static_cast<SkLayerDrawLooper::Builder*>(aBuilder)->detachLooper();
+}
+
+extern "C" SkiaSkDrawLooperRef
+SkiaSkBlurDrawLooperCreate(uint32_t aColor, float aSigma, float aDx, float aDy, uint32_t aFlags) {
+    if (!SkScalarIsFinite(aSigma) || aSigma < 0 ||
+        !SkScalarIsFinite(aDx) || !SkScalarIsFinite(aDy) ||
+        (aFlags & ~SkBlurDrawLooper::kAll_BlurFlag) != 0) {
+        return NULL;
+    }
+    return SkBlurDrawLooper::Create(aColor, aSigma, aDx, aDy, aFlags);
+}
+
+extern "C" void
+SkiaSkDrawLooperRetain(SkiaSkDrawLooperRef aLooper) {
+    SkSafeRef(static_cast<SkDrawLooper*>(aLooper));
+}
+
+extern "C" void
+SkiaSkDrawLooperRelease(SkiaSkDrawLooperRef aLooper) {
+    SkSafeUnref(static_cast<SkDrawLooper*>(aLooper));
+}
+
+extern "C" void
+SkiaSkPaintSetLooper(SkiaSkPaintRef aPaint, SkiaSkDrawLooperRef aLooper) {
+    static_cast<SkPaint*>(aPaint)->setLooper(static_cast<SkDrawLooper*>(aLooper));
+}
+
+extern "C" SkiaSkPictureRecorderRef
+SkiaSkPictureRecorderCreate() {
+    return new SkPictureRecorder();
+}
+
+extern "C" void
+SkiaSkPictureRecorderDelete(SkiaSkPictureRecorderRef aRecorder) {
+    delete static_cast<SkPictureRecorder*>(aRecorder);
+}
+
+extern "C" SkiaSkCanvasRef
+SkiaSkPictureRecorderBeginRecording(SkiaSkPictureRecorderRef aRecorder,
+                                    int32_t aWidth,
+                                    int32_t aHeight) {
+    if (aWidth <= 0 || aHeight <= 0) {
+        return NULL;
+    }
+    return static_cast<SkPictureRecorder*>(aRecorder)->beginRecording(aWidth, aHeight);
+}
+
+extern "C" SkiaSkPictureRef
+SkiaSkPictureRecorderEndRecording(SkiaSkPictureRecorderRef aRecorder) {
+    SkPictureRecorder* recorder = static_cast<SkPictureRecorder*>(aRecorder);
+    if (NULL == recorder->getRecordingCanvas()) {
+        return NULL;
+    }
+    return recorder->endRecording();
+}
+
+extern "C" void
+SkiaSkPictureRetain(SkiaSkPictureRef aPicture) {
+    SkSafeRef(static_cast<SkPicture*>(aPicture));
+}
+
+extern "C" void
+SkiaSkPictureRelease(SkiaSkPictureRef aPicture) {
+    SkSafeUnref(static_cast<SkPicture*>(aPicture));
+}
+
+extern "C" int32_t
+SkiaSkPictureGetWidth(SkiaSkPictureRef aPicture) {
+    return static_cast<SkPicture*>(aPicture)->width();
+}
+
+extern "C" int32_t
+SkiaSkPictureGetHeight(SkiaSkPictureRef aPicture) {
+    return static_cast<SkPicture*>(aPicture)->height();
+}
+
+extern "C" void
+SkiaSkCanvasDrawPicture(SkiaSkCanvasRef aCanvas, SkiaSkPictureRef aPicture) {
+    static_cast<SkCanvas*>(aCanvas)->drawPicture(static_cast<SkPicture*>(aPicture));
+}
+
+static bool
+IsValidTileMode(int32_t aTileMode) {
+    return aTileMode >= 0 && aTileMode < SkShader::kTileModeCount;
+}
+
+// Wraps the pixels or texture of an image in a bitmap without copying them,
+// for the APIs that only take bitmaps.
+static bool
+ImageToBitmap(SkImage* aImage, SkBitmap* aResult) {
+    GrTexture* texture = aImage->getTexture();
+    if (NULL == texture) {
+        return static_cast<SkImage_Base*>(aImage)->getROPixels(aResult);
+    }
+    SkColorType colorType;
+    if (!GrPixelConfig2ColorType(texture->config(), &colorType)) {
+        return false;
+    }
+    SkImageInfo info = SkImageInfo::Make(aImage->width(), aImage->height(),
+                                         colorType, kPremul_SkAlphaType);
+    aResult->setInfo(info);
+    aResult->setPixelRef(SkNEW_ARGS(SkGrPixelRef, (info, texture)))->unref();
+    return true;
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkShaderCreateImage(SkiaSkImageRef aImage,
+                        int32_t aTileModeX,
+                        int32_t aTileModeY,
+                        const SkiaMatrix* aLocalMatrix) {
+    SkBitmap bitmap;
+    if (!IsValidTileMode(aTileModeX) || !IsValidTileMode(aTileModeY) ||
+        !ImageToBitmap(static_cast<SkImage*>(aImage), &bitmap)) {
+        return NULL;
+    }
+    SkiaLocalMatrix localMatrix(aLocalMatrix);
+    return SkShader::CreateBitmapShader(bitmap,
+                                        static_cast<SkShader::TileMode>(aTileModeX),
+                                        static_cast<SkShader::TileMode>(aTileModeY),
+                                        localMatrix.get());
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkShaderCreatePicture(SkiaSkPictureRef aPicture,
+                          int32_t aTileModeX,
+                          int32_t aTileModeY,
+                          const SkiaMatrix* aLocalMatrix) {
+    if (!IsValidTileMode(aTileModeX) || !IsValidTileMode(aTileModeY)) {
+        return NULL;
+    }
+    SkiaLocalMatrix localMatrix(aLocalMatrix);
+    return SkShader::CreatePictureShader(static_cast<SkPicture*>(aPicture),
+                                         static_cast<SkShader::TileMode>(aTileModeX),
+                                         static_cast<SkShader::TileMode>(aTileModeY),
+                                         localMatrix.get());
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkShaderCreatePerlinNoise(bool aTurbulence,
+                              float aBaseFrequencyX,
+                              float aBaseFrequencyY,
+                              int32_t aOctaves,
+                              float aSeed,
+                              int32_t aTileWidth,
+                              int32_t aTileHeight) {
+    if (!SkScalarIsFinite(aBaseFrequencyX) || aBaseFrequencyX < 0 ||
+        !SkScalarIsFinite(aBaseFrequencyY) || aBaseFrequencyY < 0 ||
+        aOctaves < 0 || !SkScalarIsFinite(aSeed) ||
+        aTileWidth < 0 || aTileHeight < 0) {
+        return NULL;
+    }
+    // Each octave is another noise lookup per pixel at half the amplitude of
+    // the last, so past 10 they cost time without visibly changing the result.
+    aOctaves = SkTMin(aOctaves, 10);
+    SkISize tileSize = SkISize::Make(aTileWidth, aTileHeight);
+    if (aTurbulence) {
+        return SkPerlinNoiseShader::CreateTurbulence(aBaseFrequencyX, aBaseFrequencyY,
+                                                     aOctaves, aSeed, &tileSize);
+    }
+    return SkPerlinNoiseShader::CreateFractalNoise(aBaseFrequencyX, aBaseFrequencyY,
+                                                   aOctaves, aSeed, &tileSize);
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkShaderCreateCompose(SkiaSkShaderRef aDst, SkiaSkShaderRef aSrc, int32_t aMode) {
+    if (!IsValidXfermode(aMode)) {
+        return NULL;
+    }
+    // This is NULL for kSrcOver_Mode, which SkComposeShader expects.
+    SkAutoTUnref<SkXfermode> xfermode(SkXfermode::Create(static_cast<SkXfermode::Mode>(aMode)));
+    return SkNEW_ARGS(SkComposeShader, (static_cast<SkShader*>(aDst),
+                                        static_cast<SkShader*>(aSrc),
+                                        xfermode));
+}
+
+extern "C" SkiaSkShaderRef
+SkiaSkShaderCreateLocalMatrix(SkiaSkShaderRef aShader, const SkiaMatrix* aLocalMatrix) {
+    return SkShader::CreateLocalMatrixShader(static_cast<SkShader*>(aShader),
+                                             ToSkMatrix(aLocalMatrix));
+}
+
+static bool
+ToSkFontStyle(const SkiaFontStyle* aStyle, SkFontStyle* aResult) {
+    if (aStyle->weight < SkFontStyle::kThin_Weight ||
+        aStyle->weight > SkFontStyle::kBlack_Weight ||
+        aStyle->width < SkFontStyle::kUltraCondensed_Width ||
+        aStyle->width > SkFontStyle::kUltaExpanded_Width ||
+        aStyle->slant < SkFontStyle::kUpright_Slant ||
+        aStyle->slant > SkFontStyle::kItalic_Slant) {
+        return false;
+    }
+    *aResult = SkFontStyle(aStyle->weight,
+                           aStyle->width,
+                           static_cast<SkFontStyle::Slant>(aStyle->slant));
+    return true;
+}
+
+static void
+FromSkFontStyle(const SkFontStyle& aStyle, SkiaFontStyle* aResult) {
+    aResult->weight = aStyle.weight();
+    aResult->width = aStyle.width();
+    aResult->slant = aStyle.slant();
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkTypefaceCreateFromFile(const char aPath[], int32_t aTTCIndex) {
+    if (aTTCIndex < 0) {
+        return NULL;
+    }
+    SkAutoTUnref<SkFontMgr> fontMgr(SkFontMgr::RefDefault());
+    return fontMgr->createFromFile(aPath, aTTCIndex);
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkTypefaceCreateFromData(const void* aData, size_t aLength, int32_t aTTCIndex) {
+    if (aTTCIndex < 0) {
+        return NULL;
+    }
+    SkAutoTUnref<SkData> data(SkData::NewWithCopy(aData, aLength));
+    SkAutoTUnref<SkFontMgr> fontMgr(SkFontMgr::RefDefault());
+    return fontMgr->createFromData(data, aTTCIndex);
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkTypefaceCreateFromName(const char aFamilyName[], const SkiaFontStyle* aStyle) {
+    SkFontStyle style;
+    if (!ToSkFontStyle(aStyle, &style)) {
+        return NULL;
+    }
+    SkAutoTUnref<SkFontMgr> fontMgr(SkFontMgr::RefDefault());
+    return fontMgr->matchFamilyStyle(aFamilyName, style);
+}
+
+extern "C" void
+SkiaSkTypefaceRetain(SkiaSkTypefaceRef aTypeface) {
+    SkSafeRef(static_cast<SkTypeface*>(aTypeface));
+}
+
+extern "C" void
+SkiaSkTypefaceRelease(SkiaSkTypefaceRef aTypeface) {
+    SkSafeUnref(static_cast<SkTypeface*>(aTypeface));
+}
+
+// Copies as much of aString as fits into aBuffer and returns its length, so
+// that callers can size their buffer with a first call.
+static int32_t
+CopySkString(const SkString& aString, char* aBuffer, int32_t aCapacity) {
+    if (aCapacity > 0) {
+        memcpy(aBuffer, aString.c_str(), SkTMin<size_t>(aString.size(), aCapacity));
+    }
+    return SkToS32(aString.size());
+}
+
+extern "C" int32_t
+SkiaSkTypefaceGetFamilyName(SkiaSkTypefaceRef aTypeface, char* aBuffer, int32_t aCapacity) {
+    SkString familyName;
+    static_cast<SkTypeface*>(aTypeface)->getFamilyName(&familyName);
+    return CopySkString(familyName, aBuffer, aCapacity);
+}
+
+static uint16_t
+ReadBigEndian16(const uint8_t* aBytes) {
+    return (aBytes[0] << 8) | aBytes[1];
+}
+
+extern "C" void
+SkiaSkTypefaceGetStyle(SkiaSkTypefaceRef aTypeface, SkiaFontStyle* aStyle) {
+    const SkTypeface* typeface = static_cast<SkTypeface*>(aTypeface);
+    // SkTypeface only keeps bold and italic bits, so read the weight and
+    // width classes out of the OS/2 table when the font has one.
+    static const SkFontTableTag kOS2Tag = SkSetFourByteTag('O', 'S', '/', '2');
+    static const size_t kWeightClassOffset = 4;
+    static const size_t kWidthClassOffset = 6;
+    static const size_t kSelectionOffset = 62;
+    static const uint16_t kItalicSelection = 1 << 0;
+    static const uint16_t kObliqueSelection = 1 << 9;
+    uint8_t os2[kSelectionOffset + 2];
+    if (typeface->getTableData(kOS2Tag, 0, sizeof(os2), os2) == sizeof(os2)) {
+        uint16_t weight = ReadBigEndian16(os2 + kWeightClassOffset);
+        uint16_t width = ReadBigEndian16(os2 + kWidthClassOffset);
+        uint16_t selection = ReadBigEndian16(os2 + kSelectionOffset);
+        aStyle->weight = SkPin32(weight, SkFontStyle::kThin_Weight, SkFontStyle::kBlack_Weight);
+        aStyle->width = SkPin32(width,
+                                SkFontStyle::kUltraCondensed_Width,
+                                SkFontStyle::kUltaExpanded_Width);
+        aStyle->slant = (selection & (kItalicSelection | kObliqueSelection))
+                        ? SkFontStyle::kItalic_Slant
+                        : SkFontStyle::kUpright_Slant;
+        return;
+    }
+    FromSkFontStyle(SkFontStyle(typeface->isBold() ? SkFontStyle::kBold_Weight
+                                                   : SkFontStyle::kNormal_Weight,
+                                SkFontStyle::kNormal_Width,
+                                typeface->isItalic() ? SkFontStyle::kItalic_Slant
+                                                     : SkFontStyle::kUpright_Slant),
+                    aStyle);
+}
+
+extern "C" int32_t
+SkiaSkTypefaceGetUnitsPerEm(SkiaSkTypefaceRef aTypeface) {
+    return static_cast<SkTypeface*>(aTypeface)->getUnitsPerEm();
+}
+
+extern "C" int32_t
+SkiaSkTypefaceCountGlyphs(SkiaSkTypefaceRef aTypeface) {
+    return static_cast<SkTypeface*>(aTypeface)->countGlyphs();
+}
+
+extern "C" SkiaSkFontMgrRef
+SkiaSkFontMgrRefDefault() {
+    return SkFontMgr::RefDefault();
+}
+
+extern "C" void
+SkiaSkFontMgrRetain(SkiaSkFontMgrRef aFontMgr) {
+    SkSafeRef(static_cast<SkFontMgr*>(aFontMgr));
+}
+
+extern "C" void
+SkiaSkFontMgrRelease(SkiaSkFontMgrRef aFontMgr) {
+    SkSafeUnref(static_cast<SkFontMgr*>(aFontMgr));
+}
+
+extern "C" int32_t
+SkiaSkFontMgrCountFamilies(SkiaSkFontMgrRef aFontMgr) {
+    return static_cast<SkFontMgr*>(aFontMgr)->countFamilies();
+}
+
+extern "C" int32_t
+SkiaSkFontMgrGetFamilyName(SkiaSkFontMgrRef aFontMgr, int32_t aIndex,
+                           char* aBuffer, int32_t aCapacity) {
+    SkFontMgr* fontMgr = static_cast<SkFontMgr*>(aFontMgr);
+    if (aIndex < 0 || aIndex >= fontMgr->countFamilies()) {
+        return 0;
+    }
+    SkString familyName;
+    fontMgr->getFamilyName(aIndex, &familyName);
+    return CopySkString(familyName, aBuffer, aCapacity);
+}
+
+extern "C" SkiaSkFontStyleSetRef
+SkiaSkFontMgrCreateStyleSet(SkiaSkFontMgrRef aFontMgr, int32_t aIndex) {
+    SkFontMgr* fontMgr = static_cast<SkFontMgr*>(aFontMgr);
+    if (aIndex < 0 || aIndex >= fontMgr->countFamilies()) {
+        return NULL;
+    }
+    return fontMgr->createStyleSet(aIndex);
+}
+
+extern "C" SkiaSkFontStyleSetRef
+SkiaSkFontMgrMatchFamily(SkiaSkFontMgrRef aFontMgr, const char aFamilyName[]) {
+    return static_cast<SkFontMgr*>(aFontMgr)->matchFamily(aFamilyName);
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkFontMgrMatchFamilyStyle(SkiaSkFontMgrRef aFontMgr, const char aFamilyName[],
+                              const SkiaFontStyle* aStyle) {
+    SkFontStyle style;
+    if (!ToSkFontStyle(aStyle, &style)) {
+        return NULL;
+    }
+    return static_cast<SkFontMgr*>(aFontMgr)->matchFamilyStyle(aFamilyName, style);
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkFontMgrMatchFamilyStyleCharacter(SkiaSkFontMgrRef aFontMgr, const char aFamilyName[],
+                                       const SkiaFontStyle* aStyle, const char aBcp47[],
+                                       uint32_t aCharacter) {
+    SkFontStyle style;
+    if (!ToSkFontStyle(aStyle, &style) || aCharacter > 0x10FFFF) {
+        return NULL;
+    }
+    return static_cast<SkFontMgr*>(aFontMgr)->matchFamilyStyleCharacter(aFamilyName, style,
+                                                                         aBcp47, aCharacter);
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkFontMgrMatchFaceStyle(SkiaSkFontMgrRef aFontMgr, SkiaSkTypefaceRef aTypeface,
+                            const SkiaFontStyle* aStyle) {
+    SkFontStyle style;
+    if (!ToSkFontStyle(aStyle, &style)) {
+        return NULL;
+    }
+    return static_cast<SkFontMgr*>(aFontMgr)->matchFaceStyle(
+        static_cast<SkTypeface*>(aTypeface), style);
+}
+
+extern "C" void
+SkiaSkFontStyleSetRetain(SkiaSkFontStyleSetRef aStyleSet) {
+    SkSafeRef(static_cast<SkFontStyleSet*>(aStyleSet));
+}
+
+extern "C" void
+SkiaSkFontStyleSetRelease(SkiaSkFontStyleSetRef aStyleSet) {
+    SkSafeUnref(static_cast<SkFontStyleSet*>(aStyleSet));
+}
+
+extern "C" int32_t
+SkiaSkFontStyleSetCount(SkiaSkFontStyleSetRef aStyleSet) {
+    return static_cast<SkFontStyleSet*>(aStyleSet)->count();
+}
+
+extern "C" int32_t
+SkiaSkFontStyleSetGetStyle(SkiaSkFontStyleSetRef aStyleSet, int32_t aIndex,
+                           SkiaFontStyle* aStyle, char* aNameBuffer, int32_t aNameCapacity) {
+    SkFontStyleSet* styleSet = static_cast<SkFontStyleSet*>(aStyleSet);
+    if (aIndex < 0 || aIndex >= styleSet->count()) {
+        return 0;
+    }
+    SkFontStyle style;
+    SkString styleName;
+    styleSet->getStyle(aIndex, &style, &styleName);
+    FromSkFontStyle(style, aStyle);
+    return CopySkString(styleName, aNameBuffer, aNameCapacity);
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkFontStyleSetCreateTypeface(SkiaSkFontStyleSetRef aStyleSet, int32_t aIndex) {
+    SkFontStyleSet* styleSet = static_cast<SkFontStyleSet*>(aStyleSet);
+    if (aIndex < 0 || aIndex >= styleSet->count()) {
+        return NULL;
+    }
+    return styleSet->createTypeface(aIndex);
+}
+
+extern "C" SkiaSkTypefaceRef
+SkiaSkFontStyleSetMatchStyle(SkiaSkFontStyleSetRef aStyleSet, const SkiaFontStyle* aStyle) {
+    SkFontStyle style;
+    if (!ToSkFontStyle(aStyle, &style)) {
+        return NULL;
+    }
+    return static_cast<SkFontStyleSet*>(aStyleSet)->matchStyle(style);
+}
+
+#ifdef SKIA_C_ENABLE_FONTCONFIG
+// The out parameters of an SkFontConfigInterface match, filled in by
+// SkiaFontMatchSet.
+struct SkiaFontMatch {
+    SkFontConfigInterface::FontIdentity* mIdentity;
+    SkString* mFamilyName;
+    bool mFound;
+};
+
+static void
+FromFontIdentity(const SkFontConfigInterface::FontIdentity& aIdentity,
+                 SkiaFontIdentity* aResult) {
+    aResult->id = aIdentity.fID;
+    aResult->ttcIndex = aIdentity.fTTCIndex;
+    aResult->string = aIdentity.fString.c_str();
+    aResult->stringLength = aIdentity.fString.size();
+    FromSkFontStyle(aIdentity.fStyle, &aResult->style);
+}
+
+// An SkFontConfigInterface whose work is done by SkiaFontConfigCallbacks.
+class SkiaFontConfigInterface : public SkFontConfigInterface {
+public:
+    SkiaFontConfigInterface(const SkiaFontConfigCallbacks* aCallbacks, void* aClosure)
+        : mCallbacks(*aCallbacks)
+        , mClosure(aClosure) {}
+
+    virtual ~SkiaFontConfigInterface() {
+        mCallbacks.destroy(mClosure);
+    }
+
+    virtual bool matchFamilyName(const char aFamilyName[],
+                                 SkTypeface::Style aRequested,
+                                 FontIdentity* aOutIdentity,
+                                 SkString* aOutFamilyName,
+                                 SkTypeface::Style* aOutStyle) SK_OVERRIDE {
+        SkFontStyle requested((aRequested & SkTypeface::kBold) ? SkFontStyle::kBold_Weight
+                                                               : SkFontStyle::kNormal_Weight,
+                              SkFontStyle::kNormal_Width,
+                              (aRequested & SkTypeface::kItalic) ? SkFontStyle::kItalic_Slant
+                                                                 : SkFontStyle::kUpright_Slant);
+        SkiaFontStyle style;
+        FromSkFontStyle(requested, &style);
+
+        SkString familyName;
+        SkiaFontMatch result = { aOutIdentity, &familyName, false };
+        mCallbacks.matchFamilyName(mClosure, aFamilyName, &style, &result);
+        if (!result.mFound) {
+            return false;
+        }
+        if (aOutFamilyName) {
+            aOutFamilyName->swap(familyName);
+        }
+        if (aOutStyle) {
+            int bits = SkTypeface::kNormal;
+            if (aOutIdentity->fStyle.weight() >= SkFontStyle::kSemiBold_Weight) {
+                bits |= SkTypeface::kBold;
+            }
+            if (aOutIdentity->fStyle.isItalic()) {
+                bits |= SkTypeface::kItalic;
+            }
+            *aOutStyle = static_cast<SkTypeface::Style>(bits);
+        }
+        return true;
+    }
+
+    virtual SkStream* openStream(const FontIdentity& aIdentity) SK_OVERRIDE {
+        SkiaFontIdentity identity;
+        FromFontIdentity(aIdentity, &identity);
+        SkAutoTUnref<SkData> data(static_cast<SkData*>(mCallbacks.openStream(mClosure,
+                                                                              &identity)));
+        return data.get() ? SkNEW_ARGS(SkMemoryStream, (data.get())) : NULL;
+    }
+
+    virtual bool matchCharacter(const char aFamilyName[], const SkFontStyle& aStyle,
+                                const char aBcp47[], uint32_t aCharacter,
+                                FontIdentity* aOutIdentity,
+                                SkString* aOutFamilyName) SK_OVERRIDE {
+        SkiaFontStyle style;
+        FromSkFontStyle(aStyle, &style);
+
+        SkString familyName;
+        SkiaFontMatch result = { aOutIdentity, &familyName, false };
+        mCallbacks.matchCharacter(mClosure, aFamilyName, &style, aBcp47, aCharacter, &result);
+        if (!result.mFound) {
+            return false;
+        }
+        if (aOutFamilyName) {
+            aOutFamilyName->swap(familyName);
+        }
+        return true;
+    }
+
+private:
+    SkiaFontConfigCallbacks mCallbacks;
+    void* mClosure;
+};
+
+extern "C" void
+SkiaSkFontConfigInterfaceSetGlobal(const SkiaFontConfigCallbacks* aCallbacks, void* aClosure) {
+    SkAutoTUnref<SkFontConfigInterface> fci(SkNEW_ARGS(SkiaFontConfigInterface,
+                                                       (aCallbacks, aClosure)));
+    SkFontConfigInterface::SetGlobal(fci);
+}
+
+extern "C" void
+SkiaFontMatchSet(SkiaFontMatchRef aResult, const SkiaFontIdentity* aIdentity,
+                 const char* aFamilyName, size_t aFamilyNameLength) {
+    SkiaFontMatch* result = static_cast<SkiaFontMatch*>(aResult);
+    SkFontStyle style;
+    if (!ToSkFontStyle(&aIdentity->style, &style)) {
+        style = SkFontStyle();
+    }
+    result->mIdentity->fID = aIdentity->id;
+    result->mIdentity->fTTCIndex = aIdentity->ttcIndex;
+    result->mIdentity->fString.set(aIdentity->string, aIdentity->stringLength);
+    result->mIdentity->fStyle = style;
+    result->mFamilyName->set(aFamilyName, aFamilyNameLength);
+    result->mFound = true;
+}
+#endif
@@ -9,6 +9,178 @@
 
 typedef void* SkiaGrContextRef;
 typedef const void* SkiaGrGLInterfaceRef;
+typedef void* SkiaSkPathRef;
+typedef void* SkiaSkPathMeasureRef;
+typedef void* SkiaSkContourMeasureRef;
+typedef void* SkiaSkPathEffectRef;
+typedef void* SkiaSkRegionRef;
+typedef void* SkiaSkRegionIteratorRef;
+typedef void* SkiaSkPaintRef;
+typedef void* SkiaSkSurfaceRef;
+typedef void* SkiaSkCanvasRef;
+typedef void* Skia3DViewRef;
+typedef void* SkiaSkImageRef;
+typedef void* SkiaSkMovieRef;
+typedef void* SkiaSkDataRef;
+typedef void* SkiaSkShaderRef;
+typedef void* SkiaSkColorFilterRef;
+typedef void* SkiaSkImageFilterRef;
+typedef void* SkiaSkMaskFilterRef;
+typedef void* SkiaSkXfermodeRef;
+typedef void* SkiaSkDrawLooperRef;
+typedef void* SkiaSkLayerDrawLooperBuilderRef;
+typedef void* SkiaSkPictureRef;
+typedef void* SkiaSkPictureRecorderRef;
+typedef void* SkiaSkTypefaceRef;
+typedef void* SkiaSkFontMgrRef;
+typedef void* SkiaSkFontStyleSetRef;
+typedef void* SkiaFontMatchRef;
+
+typedef struct {
+    float x;
+    float y;
+} SkiaPoint;
+
+typedef struct {
+    float left;
+    float top;
+    float right;
+    float bottom;
+} SkiaRect;
+
+typedef struct {
+    int32_t left;
+    int32_t top;
+    int32_t right;
+    int32_t bottom;
+} SkiaIRect;
+
+/* An SkRRect's rectangle and its corner radii, starting at the upper left
+ * corner and going clockwise. */
+typedef struct {
+    SkiaRect rect;
+    SkiaPoint radii[4];
+} SkiaRRect;
+
+typedef struct {
+    int32_t width;
+    int32_t height;
+    int32_t colorType;
+    int32_t alphaType;
+} SkiaImageInfo;
+
+/* The nine values of an SkMatrix, in SkMatrix::kMScaleX..kMPersp2 order. */
+typedef struct {
+    float values[9];
+} SkiaMatrix;
+
+typedef struct {
+    float x;
+    float y;
+    float z;
+} SkiaPoint3D;
+
+/* The public fields of an SkCamera3D. */
+typedef struct {
+    SkiaPoint3D location;
+    SkiaPoint3D axis;
+    SkiaPoint3D zenith;
+    SkiaPoint3D observer;
+} SkiaCamera3D;
+
+/* The public fields of an SkPatch3D. */
+typedef struct {
+    SkiaPoint3D u;
+    SkiaPoint3D v;
+    SkiaPoint3D origin;
+} SkiaPatch3D;
+
+/* The sixteen values of an SkMatrix44, in column-major order. */
+typedef struct {
+    float values[16];
+} SkiaMatrix44;
+
+/* Receives bytes written to an SkWStream; returns false to abort the write. */
+typedef bool (*SkiaWriteCallback)(void* closure, const void* data, size_t length);
+
+/* Callbacks that implement an SkImageGenerator. destroy is called once, when
+   Skia no longer needs the generator. */
+typedef struct {
+    bool (*getInfo)(void* closure, SkiaImageInfo* info);
+    const void* (*getEncodedData)(void* closure, size_t* length);
+    bool (*getPixels)(void* closure, const SkiaImageInfo* info, void* pixels, size_t rowBytes);
+    void (*destroy)(void* closure);
+} SkiaImageGeneratorCallbacks;
+
+/* The colour stops and options shared by every SkGradientShader. positions
+ * may be NULL to spread the colours evenly; flags are
+ * SkGradientShader::Flags and localMatrix may be NULL. */
+typedef struct {
+    const uint32_t* colors;
+    const float* positions;
+    int32_t count;
+    int32_t tileMode;
+    uint32_t flags;
+    const SkiaMatrix* localMatrix;
+} SkiaGradient;
+
+/* An SkImageFilter::CropRect; edges are its CropEdge flags. */
+typedef struct {
+    SkiaRect rect;
+    uint32_t edges;
+} SkiaCropRect;
+
+/* A light for SkLightingImageFilter. type is 0 for a distant light, which
+ * only uses direction, 1 for a point light, which only uses location, and 2
+ * for a spot light. */
+typedef struct {
+    int32_t type;
+    SkiaPoint3D direction;
+    SkiaPoint3D location;
+    SkiaPoint3D target;
+    float specularExponent;
+    float cutoffAngle;
+    uint32_t color;
+} SkiaLight;
+
+/* A SkLayerDrawLooper::LayerInfo. paintBits are its Bits and colorMode is a
+ * SkXfermode::Mode. */
+typedef struct {
+    uint32_t paintBits;
+    int32_t colorMode;
+    SkiaPoint offset;
+    bool postTranslate;
+} SkiaLayerInfo;
+
+/* An SkFontStyle: weight from 100 to 900, width from 1 to 9 and slant 0 for
+ * upright or 1 for italic. */
+typedef struct {
+    int32_t weight;
+    int32_t width;
+    int32_t slant;
+} SkiaFontStyle;
+
+/* An SkFontConfigInterface::FontIdentity. string is whatever the interface
+ * finds the font by, such as its path, and is not NUL-terminated. */
+typedef struct {
+    uint32_t id;
+    int32_t ttcIndex;
+    const char* string;
+    size_t stringLength;
+    SkiaFontStyle style;
+} SkiaFontIdentity;
+
+/* Callbacks that implement an SkFontConfigInterface. The match callbacks
+   report a font by passing their result to SkiaFontMatchSet, and leave it
+   unset if they find none. familyName and bcp47 may be NULL. openStream
+   returns a new reference to the font's data, or NULL. destroy is called
+   once, when Skia no longer needs the interface. */
+typedef struct {
+    void (*matchFamilyName)(void* closure, const char familyName[], const SkiaFontStyle* style, SkiaFontMatchRef result);
+    SkiaSkDataRef (*openStream)(void* closure, const SkiaFontIdentity* identity);
+    void (*matchCharacter)(void* closure, const char familyName[], const SkiaFontStyle* style, const char bcp47[], uint32_t character, SkiaFontMatchRef result);
+    void (*destroy)(void* closure);
+} SkiaFontConfigCallbacks;
 
 #ifdef __cplusplus
 extern "C" {
@@ -24,6 +196,330 @@ SkiaGrContextRef SkiaGrContextCreate(SkiaGrGLInterfaceRef);
 void SkiaGrContextRetain(SkiaGrContextRef);
 void SkiaGrContextRelease(SkiaGrContextRef);
 
+SkiaSkPathRef SkiaSkPathCreate();
+SkiaSkPathRef SkiaSkPathClone(SkiaSkPathRef);
+void SkiaSkPathDelete(SkiaSkPathRef);
+void SkiaSkPathReset(SkiaSkPathRef);
+int32_t SkiaSkPathGetFillType(SkiaSkPathRef);
+void SkiaSkPathSetFillType(SkiaSkPathRef, int32_t fillType);
+bool SkiaSkPathIsEmpty(SkiaSkPathRef);
+void SkiaSkPathGetBounds(SkiaSkPathRef, SkiaRect* bounds);
+bool SkiaSkPathContains(SkiaSkPathRef, float x, float y);
+void SkiaSkPathMoveTo(SkiaSkPathRef, float x, float y);
+void SkiaSkPathLineTo(SkiaSkPathRef, float x, float y);
+void SkiaSkPathQuadTo(SkiaSkPathRef, float x1, float y1, float x2, float y2);
+void SkiaSkPathConicTo(SkiaSkPathRef, float x1, float y1, float x2, float y2, float weight);
+void SkiaSkPathCubicTo(SkiaSkPathRef, float x1, float y1, float x2, float y2, float x3, float y3);
+void SkiaSkPathClose(SkiaSkPathRef);
+void SkiaSkPathAddRect(SkiaSkPathRef, const SkiaRect* rect, bool clockwise);
+void SkiaSkPathAddOval(SkiaSkPathRef, const SkiaRect* oval, bool clockwise);
+void SkiaSkPathAddCircle(SkiaSkPathRef, float x, float y, float radius, bool clockwise);
+void SkiaSkPathAddPath(SkiaSkPathRef, SkiaSkPathRef other);
+void SkiaSkPathTransform(SkiaSkPathRef, const SkiaMatrix* matrix);
+
+bool SkiaSkPathOp(SkiaSkPathRef one, SkiaSkPathRef two, int32_t op, SkiaSkPathRef result);
+bool SkiaSkPathSimplify(SkiaSkPathRef, SkiaSkPathRef result);
+bool SkiaSkPathTightBounds(SkiaSkPathRef, SkiaRect* bounds);
+
+SkiaSkPathMeasureRef SkiaSkPathMeasureCreate(SkiaSkPathRef, bool forceClosed);
+void SkiaSkPathMeasureDelete(SkiaSkPathMeasureRef);
+SkiaSkContourMeasureRef SkiaSkPathMeasureNextContour(SkiaSkPathMeasureRef);
+void SkiaSkContourMeasureDelete(SkiaSkContourMeasureRef);
+float SkiaSkContourMeasureGetLength(SkiaSkContourMeasureRef);
+bool SkiaSkContourMeasureIsClosed(SkiaSkContourMeasureRef);
+bool SkiaSkContourMeasureGetPosTan(SkiaSkContourMeasureRef, float distance, SkiaPoint* position, SkiaPoint* tangent);
+bool SkiaSkContourMeasureGetSegment(SkiaSkContourMeasureRef, float startDistance, float stopDistance, SkiaSkPathRef result, bool startWithMoveTo);
+
+SkiaSkPathEffectRef SkiaSkPathEffectCreateDash(const float intervals[], int32_t count, float phase);
+SkiaSkPathEffectRef SkiaSkPathEffectCreateCorner(float radius);
+SkiaSkPathEffectRef SkiaSkPathEffectCreateDiscrete(float segmentLength, float deviation, uint32_t seed);
+SkiaSkPathEffectRef SkiaSkPathEffectCreatePath1D(SkiaSkPathRef path, float advance, float phase, int32_t style);
+SkiaSkPathEffectRef SkiaSkPathEffectCreateLine2D(float width, const SkiaMatrix* matrix);
+SkiaSkPathEffectRef SkiaSkPathEffectCreatePath2D(const SkiaMatrix* matrix, SkiaSkPathRef path);
+SkiaSkPathEffectRef SkiaSkPathEffectCreateCompose(SkiaSkPathEffectRef outer, SkiaSkPathEffectRef inner);
+SkiaSkPathEffectRef SkiaSkPathEffectCreateSum(SkiaSkPathEffectRef first, SkiaSkPathEffectRef second);
+void SkiaSkPathEffectRetain(SkiaSkPathEffectRef);
+void SkiaSkPathEffectRelease(SkiaSkPathEffectRef);
+bool SkiaSkPathEffectFilterPath(SkiaSkPathEffectRef, SkiaSkPathRef path, SkiaSkPathRef result);
+
+bool SkiaSkPathStroke(SkiaSkPathRef, float width, int32_t cap, int32_t join, float miterLimit, SkiaSkPathEffectRef pathEffect, SkiaSkPathRef result);
+bool SkiaSkPathStrokeContains(SkiaSkPathRef, float width, int32_t cap, int32_t join, float miterLimit, SkiaSkPathEffectRef pathEffect, float x, float y);
+
+SkiaSkRegionRef SkiaSkRegionCreate();
+SkiaSkRegionRef SkiaSkRegionClone(SkiaSkRegionRef);
+void SkiaSkRegionDelete(SkiaSkRegionRef);
+bool SkiaSkRegionEquals(SkiaSkRegionRef, SkiaSkRegionRef other);
+bool SkiaSkRegionSetEmpty(SkiaSkRegionRef);
+bool SkiaSkRegionSetRect(SkiaSkRegionRef, const SkiaIRect* rect);
+bool SkiaSkRegionSetRects(SkiaSkRegionRef, const SkiaIRect rects[], int32_t count);
+bool SkiaSkRegionSetPath(SkiaSkRegionRef, SkiaSkPathRef path, SkiaSkRegionRef clip);
+bool SkiaSkRegionIsEmpty(SkiaSkRegionRef);
+bool SkiaSkRegionIsRect(SkiaSkRegionRef);
+void SkiaSkRegionGetBounds(SkiaSkRegionRef, SkiaIRect* bounds);
+bool SkiaSkRegionGetBoundaryPath(SkiaSkRegionRef, SkiaSkPathRef result);
+bool SkiaSkRegionContainsPoint(SkiaSkRegionRef, int32_t x, int32_t y);
+bool SkiaSkRegionContainsRect(SkiaSkRegionRef, const SkiaIRect* rect);
+bool SkiaSkRegionContainsRegion(SkiaSkRegionRef, SkiaSkRegionRef other);
+bool SkiaSkRegionIntersectsRect(SkiaSkRegionRef, const SkiaIRect* rect);
+bool SkiaSkRegionIntersectsRegion(SkiaSkRegionRef, SkiaSkRegionRef other);
+void SkiaSkRegionTranslate(SkiaSkRegionRef, int32_t dx, int32_t dy);
+bool SkiaSkRegionOpRect(SkiaSkRegionRef, const SkiaIRect* rect, int32_t op);
+bool SkiaSkRegionOpRegion(SkiaSkRegionRef, SkiaSkRegionRef other, int32_t op);
+SkiaSkRegionIteratorRef SkiaSkRegionIteratorCreate(SkiaSkRegionRef);
+void SkiaSkRegionIteratorDelete(SkiaSkRegionIteratorRef);
+bool SkiaSkRegionIteratorNext(SkiaSkRegionIteratorRef, SkiaIRect* rect);
+
+void SkiaSkRRectSetRectRadii(SkiaRRect*);
+void SkiaSkRRectSetOval(SkiaRRect*, const SkiaRect* oval);
+void SkiaSkRRectSetRectXY(SkiaRRect*, const SkiaRect* rect, float xRadius, float yRadius);
+int32_t SkiaSkRRectGetType(const SkiaRRect*);
+void SkiaSkRRectInset(SkiaRRect*, float dx, float dy);
+bool SkiaSkRRectContainsRect(const SkiaRRect*, const SkiaRect* rect);
+bool SkiaSkRRectContainsPoint(const SkiaRRect*, float x, float y);
+bool SkiaSkRRectTransform(SkiaRRect*, const SkiaMatrix* matrix);
+void SkiaSkPathAddRRect(SkiaSkPathRef, const SkiaRRect* rrect, bool clockwise);
+
+SkiaSkPaintRef SkiaSkPaintCreate();
+SkiaSkPaintRef SkiaSkPaintClone(SkiaSkPaintRef);
+void SkiaSkPaintDelete(SkiaSkPaintRef);
+uint32_t SkiaSkPaintGetColor(SkiaSkPaintRef);
+void SkiaSkPaintSetColor(SkiaSkPaintRef, uint32_t color);
+bool SkiaSkPaintIsAntiAlias(SkiaSkPaintRef);
+void SkiaSkPaintSetAntiAlias(SkiaSkPaintRef, bool antiAlias);
+int32_t SkiaSkPaintGetStyle(SkiaSkPaintRef);
+void SkiaSkPaintSetStyle(SkiaSkPaintRef, int32_t style);
+void SkiaSkPaintSetStroke(SkiaSkPaintRef, float width, int32_t cap, int32_t join, float miterLimit, SkiaSkPathEffectRef pathEffect);
+
+SkiaSkSurfaceRef SkiaSkSurfaceCreateRaster(int32_t width, int32_t height);
+SkiaSkSurfaceRef SkiaSkSurfaceCreateRenderTarget(SkiaGrContextRef, int32_t width, int32_t height);
+void SkiaSkSurfaceRetain(SkiaSkSurfaceRef);
+void SkiaSkSurfaceRelease(SkiaSkSurfaceRef);
+int32_t SkiaSkSurfaceGetWidth(SkiaSkSurfaceRef);
+int32_t SkiaSkSurfaceGetHeight(SkiaSkSurfaceRef);
+SkiaSkCanvasRef SkiaSkSurfaceGetCanvas(SkiaSkSurfaceRef);
+
+int32_t SkiaSkCanvasSave(SkiaSkCanvasRef);
+void SkiaSkCanvasRestore(SkiaSkCanvasRef);
+void SkiaSkCanvasRestoreToCount(SkiaSkCanvasRef, int32_t saveCount);
+void SkiaSkCanvasTranslate(SkiaSkCanvasRef, float dx, float dy);
+void SkiaSkCanvasScale(SkiaSkCanvasRef, float sx, float sy);
+void SkiaSkCanvasRotate(SkiaSkCanvasRef, float degrees);
+void SkiaSkCanvasConcat(SkiaSkCanvasRef, const SkiaMatrix* matrix);
+void SkiaSkCanvasSetMatrix(SkiaSkCanvasRef, const SkiaMatrix* matrix);
+void SkiaSkCanvasClear(SkiaSkCanvasRef, uint32_t color);
+void SkiaSkCanvasFlush(SkiaSkCanvasRef);
+void SkiaSkCanvasClipRect(SkiaSkCanvasRef, const SkiaRect* rect, int32_t op, bool antiAlias);
+void SkiaSkCanvasClipRRect(SkiaSkCanvasRef, const SkiaRRect* rrect, int32_t op, bool antiAlias);
+void SkiaSkCanvasClipPath(SkiaSkCanvasRef, SkiaSkPathRef path, int32_t op, bool antiAlias);
+void SkiaSkCanvasClipRegion(SkiaSkCanvasRef, SkiaSkRegionRef deviceRegion, int32_t op);
+void SkiaSkCanvasDrawPaint(SkiaSkCanvasRef, SkiaSkPaintRef paint);
+void SkiaSkCanvasDrawRect(SkiaSkCanvasRef, const SkiaRect* rect, SkiaSkPaintRef paint);
+void SkiaSkCanvasDrawOval(SkiaSkCanvasRef, const SkiaRect* oval, SkiaSkPaintRef paint);
+void SkiaSkCanvasDrawRRect(SkiaSkCanvasRef, const SkiaRRect* rrect, SkiaSkPaintRef paint);
+void SkiaSkCanvasDrawDRRect(SkiaSkCanvasRef, const SkiaRRect* outer, const SkiaRRect* inner, SkiaSkPaintRef paint);
+void SkiaSkCanvasDrawPath(SkiaSkCanvasRef, SkiaSkPathRef path, SkiaSkPaintRef paint);
+void SkiaSkCanvasDrawRegion(SkiaSkCanvasRef, SkiaSkRegionRef region, SkiaSkPaintRef paint);
+
+bool SkiaSkMatrix44Invert(const SkiaMatrix44*, SkiaMatrix44* result);
+void SkiaSkMatrix44Concat(const SkiaMatrix44* a, const SkiaMatrix44* b, SkiaMatrix44* result);
+void SkiaSkMatrix44MapScalars(const SkiaMatrix44*, const float src[4], float dst[4]);
+void SkiaSkMatrix44ToSkMatrix(const SkiaMatrix44*, SkiaMatrix* result);
+void SkiaSkMatrix44FromSkMatrix(const SkiaMatrix*, SkiaMatrix44* result);
+bool SkiaSkMatrixInvert(const SkiaMatrix*, SkiaMatrix* result);
+void SkiaSkMatrixConcat(const SkiaMatrix* a, const SkiaMatrix* b, SkiaMatrix* result);
+void SkiaSkMatrixMapPoints(const SkiaMatrix*, SkiaPoint points[], int32_t count);
+
+Skia3DViewRef Skia3DViewCreate();
+void Skia3DViewDelete(Skia3DViewRef);
+void Skia3DViewSave(Skia3DViewRef);
+void Skia3DViewRestore(Skia3DViewRef);
+void Skia3DViewTranslate(Skia3DViewRef, float x, float y, float z);
+void Skia3DViewRotateX(Skia3DViewRef, float degrees);
+void Skia3DViewRotateY(Skia3DViewRef, float degrees);
+void Skia3DViewRotateZ(Skia3DViewRef, float degrees);
+void Skia3DViewGetMatrix(Skia3DViewRef, SkiaMatrix* result);
+void Skia3DViewApplyToCanvas(Skia3DViewRef, SkiaSkCanvasRef canvas);
+float Skia3DViewDotWithNormal(Skia3DViewRef, float dx, float dy, float dz);
+void SkiaSkCamera3DPatchToMatrix(const SkiaCamera3D*, const SkiaPatch3D* patch, SkiaMatrix* result);
+
+
+SkiaSkImageRef SkiaSkImageCreateFromEncoded(const void* data, size_t length, int32_t* format);
+void SkiaSkImageRetain(SkiaSkImageRef);
+void SkiaSkImageRelease(SkiaSkImageRef);
+int32_t SkiaSkImageGetWidth(SkiaSkImageRef);
+int32_t SkiaSkImageGetHeight(SkiaSkImageRef);
+void SkiaSkCanvasDrawImage(SkiaSkCanvasRef, SkiaSkImageRef image, float x, float y, SkiaSkPaintRef paint);
+
+SkiaSkImageRef SkiaSkSurfaceNewImageSnapshot(SkiaSkSurfaceRef);
+bool SkiaSkImageEncode(SkiaSkImageRef, int32_t type, int32_t quality, SkiaWriteCallback callback, void* closure);
+
+SkiaSkMovieRef SkiaSkMovieCreateFromEncoded(const void* data, size_t length);
+void SkiaSkMovieRelease(SkiaSkMovieRef);
+uint32_t SkiaSkMovieGetDuration(SkiaSkMovieRef);
+int32_t SkiaSkMovieGetWidth(SkiaSkMovieRef);
+int32_t SkiaSkMovieGetHeight(SkiaSkMovieRef);
+bool SkiaSkMovieIsOpaque(SkiaSkMovieRef);
+bool SkiaSkMovieSetTime(SkiaSkMovieRef, uint32_t time);
+SkiaSkImageRef SkiaSkMovieGetFrame(SkiaSkMovieRef);
+
+SkiaSkImageRef SkiaSkImageCreateLazyFromEncoded(const void* data, size_t length, int32_t* format);
+size_t SkiaSkDiscardableMemoryPoolGetRAMUsed();
+size_t SkiaSkDiscardableMemoryPoolGetRAMBudget();
+void SkiaSkDiscardableMemoryPoolSetRAMBudget(size_t budget);
+void SkiaSkDiscardableMemoryPoolPurge();
+
+SkiaSkImageRef SkiaSkImageCreateFromGenerator(const SkiaImageGeneratorCallbacks* callbacks, void* closure);
+
+int32_t SkiaSkPaintGetFilterLevel(SkiaSkPaintRef);
+void SkiaSkPaintSetFilterLevel(SkiaSkPaintRef, int32_t level);
+void SkiaSkCanvasDrawImageRect(SkiaSkCanvasRef, SkiaSkImageRef image, const SkiaRect* src, const SkiaRect* dst, SkiaSkPaintRef paint);
+bool SkiaSkBitmapScalerResize(const SkiaImageInfo* srcInfo, const void* srcPixels, size_t srcRowBytes, int32_t method, int32_t dstWidth, int32_t dstHeight, void* dstPixels, size_t dstRowBytes);
+
+/* Compressed formats are 0 for ETC1 and 1 for LATC. */
+SkiaSkImageRef SkiaSkImageCreateFromCompressedTexture(SkiaGrContextRef, int32_t format, int32_t width, int32_t height, const void* data, size_t length);
+bool SkiaETC1ContainerGetData(const void* data, size_t length, int32_t* width, int32_t* height, size_t* offset);
+bool SkiaETC1EncodeImage(const SkiaImageInfo* info, const void* pixels, size_t rowBytes, void* dst, size_t dstLength);
+bool SkiaSkTextureCompressorCompressLATC(const SkiaImageInfo* info, const void* pixels, size_t rowBytes, void* dst, size_t dstLength);
+
+/* srcPalette is only read when the source is Index8, which needs 1 to 256
+ * unpremultiplied colours. */
+bool SkiaConvertPixels(const SkiaImageInfo* srcInfo, const void* srcPixels, size_t srcRowBytes, const uint32_t* srcPalette, int32_t srcPaletteCount, const SkiaImageInfo* dstInfo, void* dstPixels, size_t dstRowBytes);
+
+SkiaSkDataRef SkiaSkDataCreateWithCopy(const void* data, size_t length);
+void SkiaSkDataRelease(SkiaSkDataRef);
+SkiaSkImageRef SkiaSkImageCreateIndex8(int32_t width, int32_t height, SkiaSkDataRef indices, size_t rowBytes, const uint32_t* palette, int32_t count);
+
+SkiaSkShaderRef SkiaSkGradientShaderCreateLinear(const SkiaPoint* start, const SkiaPoint* end, const SkiaGradient* gradient);
+SkiaSkShaderRef SkiaSkGradientShaderCreateRadial(const SkiaPoint* center, float radius, const SkiaGradient* gradient);
+SkiaSkShaderRef SkiaSkGradientShaderCreateTwoPointRadial(const SkiaPoint* start, float startRadius, const SkiaPoint* end, float endRadius, const SkiaGradient* gradient);
+SkiaSkShaderRef SkiaSkGradientShaderCreateTwoPointConical(const SkiaPoint* start, float startRadius, const SkiaPoint* end, float endRadius, const SkiaGradient* gradient);
+SkiaSkShaderRef SkiaSkGradientShaderCreateSweep(const SkiaPoint* center, const SkiaGradient* gradient);
+void SkiaSkShaderRetain(SkiaSkShaderRef);
+void SkiaSkShaderRelease(SkiaSkShaderRef);
+void SkiaSkPaintSetShader(SkiaSkPaintRef, SkiaSkShaderRef shader);
+
+SkiaSkColorFilterRef SkiaSkColorFilterCreateMatrix(const float matrix[20]);
+void SkiaSkColorFilterRetain(SkiaSkColorFilterRef);
+void SkiaSkColorFilterRelease(SkiaSkColorFilterRef);
+SkiaSkColorFilterRef SkiaSkColorFilterCreateTable(const uint8_t tableA[256], const uint8_t tableR[256], const uint8_t tableG[256], const uint8_t tableB[256]);
+SkiaSkColorFilterRef SkiaSkColorFilterCreateLuma();
+SkiaSkColorFilterRef SkiaSkColorFilterCreateMode(uint32_t color, int32_t mode);
+SkiaSkColorFilterRef SkiaSkColorFilterCreateLighting(uint32_t mul, uint32_t add);
+SkiaSkColorFilterRef SkiaSkColorFilterCompose(SkiaSkColorFilterRef outer, SkiaSkColorFilterRef inner);
+void SkiaSkPaintSetColorFilter(SkiaSkPaintRef, SkiaSkColorFilterRef colorFilter);
+
+/* A NULL input is the source graphic; crop rects may be NULL. */
+SkiaSkImageFilterRef SkiaSkImageFilterCreateSourceAlpha();
+SkiaSkImageFilterRef SkiaSkImageFilterCreateBlur(float sigmaX, float sigmaY, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateDropShadow(float dx, float dy, float sigmaX, float sigmaY, uint32_t color, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateMorphology(bool dilate, int32_t radiusX, int32_t radiusY, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateDisplacementMap(int32_t xChannel, int32_t yChannel, float scale, SkiaSkImageFilterRef displacement, SkiaSkImageFilterRef color, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateDiffuseLighting(const SkiaLight* light, float surfaceScale, float kd, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateSpecularLighting(const SkiaLight* light, float surfaceScale, float ks, float shininess, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateMatrixConvolution(int32_t width, int32_t height, const float kernel[], float gain, float bias, int32_t offsetX, int32_t offsetY, int32_t tileMode, bool convolveAlpha, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateColorFilter(SkiaSkColorFilterRef colorFilter, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateMerge(const SkiaSkImageFilterRef inputs[], const int32_t modes[], int32_t count, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateCompose(SkiaSkImageFilterRef outer, SkiaSkImageFilterRef inner);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateOffset(float dx, float dy, SkiaSkImageFilterRef input, const SkiaCropRect* cropRect);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateTile(const SkiaRect* src, const SkiaRect* dst, SkiaSkImageFilterRef input);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateBlend(int32_t mode, SkiaSkImageFilterRef background, SkiaSkImageFilterRef foreground, const SkiaCropRect* cropRect);
+void SkiaSkImageFilterRetain(SkiaSkImageFilterRef);
+void SkiaSkImageFilterRelease(SkiaSkImageFilterRef);
+void SkiaSkPaintSetImageFilter(SkiaSkPaintRef, SkiaSkImageFilterRef imageFilter);
+int32_t SkiaSkCanvasSaveLayer(SkiaSkCanvasRef, const SkiaRect* bounds, SkiaSkPaintRef paint);
+
+SkiaSkMaskFilterRef SkiaSkMaskFilterCreateBlur(int32_t style, float sigma, uint32_t flags);
+SkiaSkMaskFilterRef SkiaSkMaskFilterCreateEmboss(float sigma, const float direction[3], float ambient, float specular);
+SkiaSkMaskFilterRef SkiaSkMaskFilterCreateTable(const uint8_t table[256]);
+SkiaSkMaskFilterRef SkiaSkMaskFilterCreateGamma(float gamma);
+SkiaSkMaskFilterRef SkiaSkMaskFilterCreateClip(uint8_t min, uint8_t max);
+SkiaSkMaskFilterRef SkiaSkMaskFilterCreateStipple();
+void SkiaSkMaskFilterRetain(SkiaSkMaskFilterRef);
+void SkiaSkMaskFilterRelease(SkiaSkMaskFilterRef);
+void SkiaSkPaintSetMaskFilter(SkiaSkPaintRef, SkiaSkMaskFilterRef maskFilter);
+
+SkiaSkXfermodeRef SkiaSkXfermodeCreateArithmetic(float k1, float k2, float k3, float k4, bool enforcePremul);
+SkiaSkXfermodeRef SkiaSkXfermodeCreateLerp(float scale);
+SkiaSkXfermodeRef SkiaSkXfermodeCreateAvoid(uint32_t color, uint8_t tolerance, int32_t mode);
+SkiaSkXfermodeRef SkiaSkXfermodeCreatePixelXor(uint32_t color);
+void SkiaSkXfermodeRetain(SkiaSkXfermodeRef);
+void SkiaSkXfermodeRelease(SkiaSkXfermodeRef);
+bool SkiaSkXfermodeIsSupportedOnGpu(SkiaSkXfermodeRef);
+void SkiaSkPaintSetXfermode(SkiaSkPaintRef, SkiaSkXfermodeRef xfermode);
+void SkiaSkPaintSetXfermodeMode(SkiaSkPaintRef, int32_t mode);
+SkiaSkImageFilterRef SkiaSkImageFilterCreateXfermode(SkiaSkXfermodeRef xfermode, SkiaSkImageFilterRef background, SkiaSkImageFilterRef foreground, const SkiaCropRect* cropRect);
+
+/* Layers added with AddLayer draw below the ones added before them, and
+ * those added with AddLayerOnTop above. The paint is copied. */
+SkiaSkLayerDrawLooperBuilderRef SkiaSkLayerDrawLooperBuilderCreate();
+void SkiaSkLayerDrawLooperBuilderDelete(SkiaSkLayerDrawLooperBuilderRef);
+bool SkiaSkLayerDrawLooperBuilderAddLayer(SkiaSkLayerDrawLooperBuilderRef, const SkiaLayerInfo* info, SkiaSkPaintRef paint);
+bool SkiaSkLayerDrawLooperBuilderAddLayerOnTop(SkiaSkLayerDrawLooperBuilderRef, const SkiaLayerInfo* info, SkiaSkPaintRef paint);
+SkiaSkDrawLooperRef SkiaSkLayerDrawLooperBuilderDetachLooper(SkiaSkLayerDrawLooperBuilderRef);
+SkiaSkDrawLooperRef SkiaSkBlurDrawLooperCreate(uint32_t color, float sigma, float dx, float dy, uint32_t flags);
+void SkiaSkDrawLooperRetain(SkiaSkDrawLooperRef);
+void SkiaSkDrawLooperRelease(SkiaSkDrawLooperRef);
+void SkiaSkPaintSetLooper(SkiaSkPaintRef, SkiaSkDrawLooperRef looper);
+
+/* The canvas returned by BeginRecording is owned by the recorder and is only
+ * valid until EndRecording. */
+SkiaSkPictureRecorderRef SkiaSkPictureRecorderCreate();
+void SkiaSkPictureRecorderDelete(SkiaSkPictureRecorderRef);
+SkiaSkCanvasRef SkiaSkPictureRecorderBeginRecording(SkiaSkPictureRecorderRef, int32_t width, int32_t height);
+SkiaSkPictureRef SkiaSkPictureRecorderEndRecording(SkiaSkPictureRecorderRef);
+void SkiaSkPictureRetain(SkiaSkPictureRef);
+void SkiaSkPictureRelease(SkiaSkPictureRef);
+int32_t SkiaSkPictureGetWidth(SkiaSkPictureRef);
+int32_t SkiaSkPictureGetHeight(SkiaSkPictureRef);
+void SkiaSkCanvasDrawPicture(SkiaSkCanvasRef, SkiaSkPictureRef picture);
+
+/* localMatrix may be NULL. */
+SkiaSkShaderRef SkiaSkShaderCreateImage(SkiaSkImageRef image, int32_t tileModeX, int32_t tileModeY, const SkiaMatrix* localMatrix);
+SkiaSkShaderRef SkiaSkShaderCreatePicture(SkiaSkPictureRef picture, int32_t tileModeX, int32_t tileModeY, const SkiaMatrix* localMatrix);
+SkiaSkShaderRef SkiaSkShaderCreatePerlinNoise(bool turbulence, float baseFrequencyX, float baseFrequencyY, int32_t octaves, float seed, int32_t tileWidth, int32_t tileHeight);
+SkiaSkShaderRef SkiaSkShaderCreateCompose(SkiaSkShaderRef dst, SkiaSkShaderRef src, int32_t mode);
+SkiaSkShaderRef SkiaSkShaderCreateLocalMatrix(SkiaSkShaderRef, const SkiaMatrix* localMatrix);
+
+/* Typefaces come from the default SkFontMgr. GetFamilyName writes up to
+ * capacity bytes of the UTF-8 name, without a terminator, and returns its
+ * full length. */
+SkiaSkTypefaceRef SkiaSkTypefaceCreateFromFile(const char path[], int32_t ttcIndex);
+SkiaSkTypefaceRef SkiaSkTypefaceCreateFromData(const void* data, size_t length, int32_t ttcIndex);
+SkiaSkTypefaceRef SkiaSkTypefaceCreateFromName(const char familyName[], const SkiaFontStyle* style);
+void SkiaSkTypefaceRetain(SkiaSkTypefaceRef);
+void SkiaSkTypefaceRelease(SkiaSkTypefaceRef);
+int32_t SkiaSkTypefaceGetFamilyName(SkiaSkTypefaceRef, char* buffer, int32_t capacity);
+void SkiaSkTypefaceGetStyle(SkiaSkTypefaceRef, SkiaFontStyle* style);
+int32_t SkiaSkTypefaceGetUnitsPerEm(SkiaSkTypefaceRef);
+int32_t SkiaSkTypefaceCountGlyphs(SkiaSkTypefaceRef);
+
+/* Out-of-range indices give 0 or NULL. Names are copied as for
+ * SkiaSkTypefaceGetFamilyName. MatchFamily never returns NULL, giving an
+ * empty set for unknown families. bcp47 may be NULL or empty. */
+SkiaSkFontMgrRef SkiaSkFontMgrRefDefault();
+void SkiaSkFontMgrRetain(SkiaSkFontMgrRef);
+void SkiaSkFontMgrRelease(SkiaSkFontMgrRef);
+int32_t SkiaSkFontMgrCountFamilies(SkiaSkFontMgrRef);
+int32_t SkiaSkFontMgrGetFamilyName(SkiaSkFontMgrRef, int32_t index, char* buffer, int32_t capacity);
+SkiaSkFontStyleSetRef SkiaSkFontMgrCreateStyleSet(SkiaSkFontMgrRef, int32_t index);
+SkiaSkFontStyleSetRef SkiaSkFontMgrMatchFamily(SkiaSkFontMgrRef, const char familyName[]);
+SkiaSkTypefaceRef SkiaSkFontMgrMatchFamilyStyle(SkiaSkFontMgrRef, const char familyName[], const SkiaFontStyle* style);
+SkiaSkTypefaceRef SkiaSkFontMgrMatchFamilyStyleCharacter(SkiaSkFontMgrRef, const char familyName[], const SkiaFontStyle* style, const char bcp47[], uint32_t character);
+SkiaSkTypefaceRef SkiaSkFontMgrMatchFaceStyle(SkiaSkFontMgrRef, SkiaSkTypefaceRef typeface, const SkiaFontStyle* style);
+
+void SkiaSkFontStyleSetRetain(SkiaSkFontStyleSetRef);
+void SkiaSkFontStyleSetRelease(SkiaSkFontStyleSetRef);
+int32_t SkiaSkFontStyleSetCount(SkiaSkFontStyleSetRef);
+int32_t SkiaSkFontStyleSetGetStyle(SkiaSkFontStyleSetRef, int32_t index, SkiaFontStyle* style, char* nameBuffer, int32_t nameCapacity);
+SkiaSkTypefaceRef SkiaSkFontStyleSetCreateTypeface(SkiaSkFontStyleSetRef, int32_t index);
+SkiaSkTypefaceRef SkiaSkFontStyleSetMatchStyle(SkiaSkFontStyleSetRef, const SkiaFontStyle* style);
+
+/* Replaces the process's SkFontConfigInterface. The default SkFontMgr keeps
+ * the interface it was created with, so install one before loading fonts.
+ * Only in builds that use fontconfig: Linux and Android. */
+void SkiaSkFontConfigInterfaceSetGlobal(const SkiaFontConfigCallbacks* callbacks, void* closure);
+void SkiaFontMatchSet(SkiaFontMatchRef, const SkiaFontIdentity* identity, const char* familyName, size_t familyNameLength);
+
 #ifdef __cplusplus
 }
 #endif
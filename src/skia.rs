@@ -6,10 +6,317 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
+use euclid::default::{Box2D, Point2D, Point3D, Rect, Size2D, Transform2D, Vector3D};
 use libc::*;
 
 pub type SkiaGrContextRef = *mut c_void;
 pub type SkiaGrGLInterfaceRef = *const c_void;
+pub type SkiaSkPathRef = *mut c_void;
+pub type SkiaSkPathMeasureRef = *mut c_void;
+pub type SkiaSkContourMeasureRef = *mut c_void;
+pub type SkiaSkPathEffectRef = *mut c_void;
+pub type SkiaSkRegionRef = *mut c_void;
+pub type SkiaSkRegionIteratorRef = *mut c_void;
+pub type SkiaSkPaintRef = *mut c_void;
+pub type SkiaSkSurfaceRef = *mut c_void;
+pub type SkiaSkCanvasRef = *mut c_void;
+pub type Skia3DViewRef = *mut c_void;
+pub type SkiaSkImageRef = *mut c_void;
+pub type SkiaSkMovieRef = *mut c_void;
+pub type SkiaSkDataRef = *mut c_void;
+pub type SkiaSkShaderRef = *mut c_void;
+pub type SkiaSkColorFilterRef = *mut c_void;
+pub type SkiaSkImageFilterRef = *mut c_void;
+pub type SkiaSkMaskFilterRef = *mut c_void;
+pub type SkiaSkXfermodeRef = *mut c_void;
+pub type SkiaSkDrawLooperRef = *mut c_void;
+pub type SkiaSkLayerDrawLooperBuilderRef = *mut c_void;
+pub type SkiaSkPictureRef = *mut c_void;
+pub type SkiaSkPictureRecorderRef = *mut c_void;
+pub type SkiaSkTypefaceRef = *mut c_void;
+pub type SkiaSkFontMgrRef = *mut c_void;
+pub type SkiaSkFontStyleSetRef = *mut c_void;
+pub type SkiaFontMatchRef = *mut c_void;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl From<Point2D<f32>> for SkiaPoint {
+    fn from(point: Point2D<f32>) -> SkiaPoint {
+        SkiaPoint { x: point.x, y: point.y }
+    }
+}
+
+impl From<SkiaPoint> for Point2D<f32> {
+    fn from(point: SkiaPoint) -> Point2D<f32> {
+        Point2D::new(point.x, point.y)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaRect {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl From<Rect<f32>> for SkiaRect {
+    fn from(rect: Rect<f32>) -> SkiaRect {
+        SkiaRect {
+            left: rect.origin.x,
+            top: rect.origin.y,
+            right: rect.max_x(),
+            bottom: rect.max_y(),
+        }
+    }
+}
+
+impl From<SkiaRect> for Rect<f32> {
+    fn from(rect: SkiaRect) -> Rect<f32> {
+        Rect::new(Point2D::new(rect.left, rect.top),
+                  Size2D::new(rect.right - rect.left, rect.bottom - rect.top))
+    }
+}
+
+impl From<Box2D<f32>> for SkiaRect {
+    fn from(rect: Box2D<f32>) -> SkiaRect {
+        SkiaRect {
+            left: rect.min.x,
+            top: rect.min.y,
+            right: rect.max.x,
+            bottom: rect.max.y,
+        }
+    }
+}
+
+impl From<SkiaRect> for Box2D<f32> {
+    fn from(rect: SkiaRect) -> Box2D<f32> {
+        Box2D::new(Point2D::new(rect.left, rect.top), Point2D::new(rect.right, rect.bottom))
+    }
+}
+
+/// The nine values of an `SkMatrix`, in `SkMatrix::kMScaleX..kMPersp2` order.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SkiaMatrix {
+    pub values: [f32; 9],
+}
+
+impl From<Transform2D<f32>> for SkiaMatrix {
+    fn from(transform: Transform2D<f32>) -> SkiaMatrix {
+        SkiaMatrix {
+            values: [transform.m11, transform.m21, transform.m31,
+                     transform.m12, transform.m22, transform.m32,
+                     0.0, 0.0, 1.0],
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SkiaIRect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl From<Rect<i32>> for SkiaIRect {
+    fn from(rect: Rect<i32>) -> SkiaIRect {
+        SkiaIRect {
+            left: rect.origin.x,
+            top: rect.origin.y,
+            right: rect.max_x(),
+            bottom: rect.max_y(),
+        }
+    }
+}
+
+impl From<SkiaIRect> for Rect<i32> {
+    fn from(rect: SkiaIRect) -> Rect<i32> {
+        Rect::new(Point2D::new(rect.left, rect.top),
+                  Size2D::new(rect.right - rect.left, rect.bottom - rect.top))
+    }
+}
+
+impl From<Box2D<i32>> for SkiaIRect {
+    fn from(rect: Box2D<i32>) -> SkiaIRect {
+        SkiaIRect {
+            left: rect.min.x,
+            top: rect.min.y,
+            right: rect.max.x,
+            bottom: rect.max.y,
+        }
+    }
+}
+
+impl From<SkiaIRect> for Box2D<i32> {
+    fn from(rect: SkiaIRect) -> Box2D<i32> {
+        Box2D::new(Point2D::new(rect.left, rect.top), Point2D::new(rect.right, rect.bottom))
+    }
+}
+
+/// An `SkRRect`'s rectangle and its corner radii, starting at the upper left
+/// corner and going clockwise.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaRRect {
+    pub rect: SkiaRect,
+    pub radii: [SkiaPoint; 4],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaPoint3D {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl From<Point3D<f32>> for SkiaPoint3D {
+    fn from(point: Point3D<f32>) -> SkiaPoint3D {
+        SkiaPoint3D { x: point.x, y: point.y, z: point.z }
+    }
+}
+
+impl From<Vector3D<f32>> for SkiaPoint3D {
+    fn from(vector: Vector3D<f32>) -> SkiaPoint3D {
+        SkiaPoint3D { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+
+/// The public fields of an `SkCamera3D`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaCamera3D {
+    pub location: SkiaPoint3D,
+    pub axis: SkiaPoint3D,
+    pub zenith: SkiaPoint3D,
+    pub observer: SkiaPoint3D,
+}
+
+/// The public fields of an `SkPatch3D`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaPatch3D {
+    pub u: SkiaPoint3D,
+    pub v: SkiaPoint3D,
+    pub origin: SkiaPoint3D,
+}
+
+/// The sixteen values of an `SkMatrix44`, in column-major order.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SkiaMatrix44 {
+    pub values: [f32; 16],
+}
+
+/// Receives bytes written to an `SkWStream`; returns false to abort the write.
+pub type SkiaWriteCallback = extern "C" fn(closure: *mut c_void, data: *const c_void, length: size_t) -> bool;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaImageInfo {
+    pub width: i32,
+    pub height: i32,
+    pub color_type: i32,
+    pub alpha_type: i32,
+}
+
+/// Callbacks that implement an `SkImageGenerator`. `destroy` is called once,
+/// when Skia no longer needs the generator.
+#[repr(C)]
+pub struct SkiaImageGeneratorCallbacks {
+    pub get_info: extern "C" fn(closure: *mut c_void, info: *mut SkiaImageInfo) -> bool,
+    pub get_encoded_data: extern "C" fn(closure: *mut c_void, length: *mut size_t) -> *const c_void,
+    pub get_pixels: extern "C" fn(closure: *mut c_void, info: *const SkiaImageInfo, pixels: *mut c_void, row_bytes: size_t) -> bool,
+    pub destroy: extern "C" fn(closure: *mut c_void),
+}
+
+/// The colour stops and options shared by every `SkGradientShader`.
+/// `positions` and `local_matrix` may be null.
+#[repr(C)]
+pub struct SkiaGradient {
+    pub colors: *const u32,
+    pub positions: *const f32,
+    pub count: i32,
+    pub tile_mode: i32,
+    pub flags: u32,
+    pub local_matrix: *const SkiaMatrix,
+}
+
+/// An `SkImageFilter::CropRect`; `edges` are its `CropEdge` flags.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaCropRect {
+    pub rect: SkiaRect,
+    pub edges: u32,
+}
+
+/// A light for `SkLightingImageFilter`. `type_` is 0 for a distant light,
+/// which only uses `direction`, 1 for a point light, which only uses
+/// `location`, and 2 for a spot light.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaLight {
+    pub type_: i32,
+    pub direction: SkiaPoint3D,
+    pub location: SkiaPoint3D,
+    pub target: SkiaPoint3D,
+    pub specular_exponent: f32,
+    pub cutoff_angle: f32,
+    pub color: u32,
+}
+
+/// A `SkLayerDrawLooper::LayerInfo`. `paint_bits` are its `Bits` and
+/// `color_mode` is a `SkXfermode::Mode`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkiaLayerInfo {
+    pub paint_bits: u32,
+    pub color_mode: i32,
+    pub offset: SkiaPoint,
+    pub post_translate: bool,
+}
+
+/// An `SkFontStyle`: `weight` from 100 to 900, `width` from 1 to 9 and
+/// `slant` 0 for upright or 1 for italic.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SkiaFontStyle {
+    pub weight: i32,
+    pub width: i32,
+    pub slant: i32,
+}
+
+/// An `SkFontConfigInterface::FontIdentity`. `string` is whatever the
+/// interface finds the font by, such as its path, and is not NUL-terminated.
+#[repr(C)]
+pub struct SkiaFontIdentity {
+    pub id: u32,
+    pub ttc_index: i32,
+    pub string: *const c_char,
+    pub string_length: size_t,
+    pub style: SkiaFontStyle,
+}
+
+/// Callbacks that implement an `SkFontConfigInterface`. The match callbacks
+/// report a font by passing their result to `SkiaFontMatchSet`, and leave it
+/// unset if they find none. `destroy` is called once, when Skia no longer
+/// needs the interface.
+#[repr(C)]
+pub struct SkiaFontConfigCallbacks {
+    pub match_family_name: extern "C" fn(closure: *mut c_void, family_name: *const c_char, style: *const SkiaFontStyle, result: SkiaFontMatchRef),
+    pub open_stream: extern "C" fn(closure: *mut c_void, identity: *const SkiaFontIdentity) -> SkiaSkDataRef,
+    pub match_character: extern "C" fn(closure: *mut c_void, family_name: *const c_char, style: *const SkiaFontStyle, bcp47: *const c_char, character: u32, result: SkiaFontMatchRef),
+    pub destroy: extern "C" fn(closure: *mut c_void),
+}
 
 extern {
 
@@ -23,4 +330,381 @@ pub fn SkiaGrContextCreate(anInterface: SkiaGrGLInterfaceRef) -> SkiaGrContextRe
 pub fn SkiaGrContextRetain(aContext: SkiaGrContextRef);
 pub fn SkiaGrContextRelease(aContext: SkiaGrContextRef);
 
+pub fn SkiaSkPathCreate() -> SkiaSkPathRef;
+pub fn SkiaSkPathClone(aPath: SkiaSkPathRef) -> SkiaSkPathRef;
+pub fn SkiaSkPathDelete(aPath: SkiaSkPathRef);
+pub fn SkiaSkPathReset(aPath: SkiaSkPathRef);
+pub fn SkiaSkPathGetFillType(aPath: SkiaSkPathRef) -> i32;
+pub fn SkiaSkPathSetFillType(aPath: SkiaSkPathRef, fillType: i32);
+pub fn SkiaSkPathIsEmpty(aPath: SkiaSkPathRef) -> bool;
+pub fn SkiaSkPathGetBounds(aPath: SkiaSkPathRef, bounds: *mut SkiaRect);
+pub fn SkiaSkPathContains(aPath: SkiaSkPathRef, x: f32, y: f32) -> bool;
+pub fn SkiaSkPathMoveTo(aPath: SkiaSkPathRef, x: f32, y: f32);
+pub fn SkiaSkPathLineTo(aPath: SkiaSkPathRef, x: f32, y: f32);
+pub fn SkiaSkPathQuadTo(aPath: SkiaSkPathRef, x1: f32, y1: f32, x2: f32, y2: f32);
+pub fn SkiaSkPathConicTo(aPath: SkiaSkPathRef, x1: f32, y1: f32, x2: f32, y2: f32, weight: f32);
+pub fn SkiaSkPathCubicTo(aPath: SkiaSkPathRef, x1: f32, y1: f32, x2: f32, y2: f32, x3: f32, y3: f32);
+pub fn SkiaSkPathClose(aPath: SkiaSkPathRef);
+pub fn SkiaSkPathAddRect(aPath: SkiaSkPathRef, rect: *const SkiaRect, clockwise: bool);
+pub fn SkiaSkPathAddOval(aPath: SkiaSkPathRef, oval: *const SkiaRect, clockwise: bool);
+pub fn SkiaSkPathAddCircle(aPath: SkiaSkPathRef, x: f32, y: f32, radius: f32, clockwise: bool);
+pub fn SkiaSkPathAddPath(aPath: SkiaSkPathRef, other: SkiaSkPathRef);
+pub fn SkiaSkPathTransform(aPath: SkiaSkPathRef, matrix: *const SkiaMatrix);
+
+pub fn SkiaSkPathOp(one: SkiaSkPathRef, two: SkiaSkPathRef, op: i32, result: SkiaSkPathRef) -> bool;
+pub fn SkiaSkPathSimplify(aPath: SkiaSkPathRef, result: SkiaSkPathRef) -> bool;
+pub fn SkiaSkPathTightBounds(aPath: SkiaSkPathRef, bounds: *mut SkiaRect) -> bool;
+
+pub fn SkiaSkPathMeasureCreate(aPath: SkiaSkPathRef, forceClosed: bool) -> SkiaSkPathMeasureRef;
+pub fn SkiaSkPathMeasureDelete(aPathMeasure: SkiaSkPathMeasureRef);
+pub fn SkiaSkPathMeasureNextContour(aPathMeasure: SkiaSkPathMeasureRef) -> SkiaSkContourMeasureRef;
+pub fn SkiaSkContourMeasureDelete(aContourMeasure: SkiaSkContourMeasureRef);
+pub fn SkiaSkContourMeasureGetLength(aContourMeasure: SkiaSkContourMeasureRef) -> f32;
+pub fn SkiaSkContourMeasureIsClosed(aContourMeasure: SkiaSkContourMeasureRef) -> bool;
+pub fn SkiaSkContourMeasureGetPosTan(aContourMeasure: SkiaSkContourMeasureRef,
+                                     distance: f32,
+                                     position: *mut SkiaPoint,
+                                     tangent: *mut SkiaPoint)
+                                     -> bool;
+pub fn SkiaSkContourMeasureGetSegment(aContourMeasure: SkiaSkContourMeasureRef,
+                                      startDistance: f32,
+                                      stopDistance: f32,
+                                      result: SkiaSkPathRef,
+                                      startWithMoveTo: bool)
+                                      -> bool;
+
+pub fn SkiaSkPathEffectCreateDash(intervals: *const f32, count: i32, phase: f32) -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectCreateCorner(radius: f32) -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectCreateDiscrete(segmentLength: f32, deviation: f32, seed: u32) -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectCreatePath1D(path: SkiaSkPathRef,
+                                    advance: f32,
+                                    phase: f32,
+                                    style: i32)
+                                    -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectCreateLine2D(width: f32, matrix: *const SkiaMatrix) -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectCreatePath2D(matrix: *const SkiaMatrix, path: SkiaSkPathRef) -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectCreateCompose(outer: SkiaSkPathEffectRef,
+                                     inner: SkiaSkPathEffectRef)
+                                     -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectCreateSum(first: SkiaSkPathEffectRef,
+                                 second: SkiaSkPathEffectRef)
+                                 -> SkiaSkPathEffectRef;
+pub fn SkiaSkPathEffectRetain(aPathEffect: SkiaSkPathEffectRef);
+pub fn SkiaSkPathEffectRelease(aPathEffect: SkiaSkPathEffectRef);
+pub fn SkiaSkPathEffectFilterPath(aPathEffect: SkiaSkPathEffectRef,
+                                  path: SkiaSkPathRef,
+                                  result: SkiaSkPathRef)
+                                  -> bool;
+
+pub fn SkiaSkPathStroke(aPath: SkiaSkPathRef,
+                        width: f32,
+                        cap: i32,
+                        join: i32,
+                        miterLimit: f32,
+                        pathEffect: SkiaSkPathEffectRef,
+                        result: SkiaSkPathRef)
+                        -> bool;
+pub fn SkiaSkPathStrokeContains(aPath: SkiaSkPathRef,
+                                width: f32,
+                                cap: i32,
+                                join: i32,
+                                miterLimit: f32,
+                                pathEffect: SkiaSkPathEffectRef,
+                                x: f32,
+                                y: f32)
+                                -> bool;
+
+pub fn SkiaSkRegionCreate() -> SkiaSkRegionRef;
+pub fn SkiaSkRegionClone(aRegion: SkiaSkRegionRef) -> SkiaSkRegionRef;
+pub fn SkiaSkRegionDelete(aRegion: SkiaSkRegionRef);
+pub fn SkiaSkRegionEquals(aRegion: SkiaSkRegionRef, other: SkiaSkRegionRef) -> bool;
+pub fn SkiaSkRegionSetEmpty(aRegion: SkiaSkRegionRef) -> bool;
+pub fn SkiaSkRegionSetRect(aRegion: SkiaSkRegionRef, rect: *const SkiaIRect) -> bool;
+pub fn SkiaSkRegionSetRects(aRegion: SkiaSkRegionRef, rects: *const SkiaIRect, count: i32) -> bool;
+pub fn SkiaSkRegionSetPath(aRegion: SkiaSkRegionRef, path: SkiaSkPathRef, clip: SkiaSkRegionRef) -> bool;
+pub fn SkiaSkRegionIsEmpty(aRegion: SkiaSkRegionRef) -> bool;
+pub fn SkiaSkRegionIsRect(aRegion: SkiaSkRegionRef) -> bool;
+pub fn SkiaSkRegionGetBounds(aRegion: SkiaSkRegionRef, bounds: *mut SkiaIRect);
+pub fn SkiaSkRegionGetBoundaryPath(aRegion: SkiaSkRegionRef, result: SkiaSkPathRef) -> bool;
+pub fn SkiaSkRegionContainsPoint(aRegion: SkiaSkRegionRef, x: i32, y: i32) -> bool;
+pub fn SkiaSkRegionContainsRect(aRegion: SkiaSkRegionRef, rect: *const SkiaIRect) -> bool;
+pub fn SkiaSkRegionContainsRegion(aRegion: SkiaSkRegionRef, other: SkiaSkRegionRef) -> bool;
+pub fn SkiaSkRegionIntersectsRect(aRegion: SkiaSkRegionRef, rect: *const SkiaIRect) -> bool;
+pub fn SkiaSkRegionIntersectsRegion(aRegion: SkiaSkRegionRef, other: SkiaSkRegionRef) -> bool;
+pub fn SkiaSkRegionTranslate(aRegion: SkiaSkRegionRef, dx: i32, dy: i32);
+pub fn SkiaSkRegionOpRect(aRegion: SkiaSkRegionRef, rect: *const SkiaIRect, op: i32) -> bool;
+pub fn SkiaSkRegionOpRegion(aRegion: SkiaSkRegionRef, other: SkiaSkRegionRef, op: i32) -> bool;
+pub fn SkiaSkRegionIteratorCreate(aRegion: SkiaSkRegionRef) -> SkiaSkRegionIteratorRef;
+pub fn SkiaSkRegionIteratorDelete(anIterator: SkiaSkRegionIteratorRef);
+pub fn SkiaSkRegionIteratorNext(anIterator: SkiaSkRegionIteratorRef, rect: *mut SkiaIRect) -> bool;
+
+pub fn SkiaSkRRectSetRectRadii(aRRect: *mut SkiaRRect);
+pub fn SkiaSkRRectSetOval(aRRect: *mut SkiaRRect, oval: *const SkiaRect);
+pub fn SkiaSkRRectSetRectXY(aRRect: *mut SkiaRRect, rect: *const SkiaRect, xRadius: f32, yRadius: f32);
+pub fn SkiaSkRRectGetType(aRRect: *const SkiaRRect) -> i32;
+pub fn SkiaSkRRectInset(aRRect: *mut SkiaRRect, dx: f32, dy: f32);
+pub fn SkiaSkRRectContainsRect(aRRect: *const SkiaRRect, rect: *const SkiaRect) -> bool;
+pub fn SkiaSkRRectContainsPoint(aRRect: *const SkiaRRect, x: f32, y: f32) -> bool;
+pub fn SkiaSkRRectTransform(aRRect: *mut SkiaRRect, matrix: *const SkiaMatrix) -> bool;
+pub fn SkiaSkPathAddRRect(aPath: SkiaSkPathRef, rrect: *const SkiaRRect, clockwise: bool);
+
+pub fn SkiaSkPaintCreate() -> SkiaSkPaintRef;
+pub fn SkiaSkPaintClone(aPaint: SkiaSkPaintRef) -> SkiaSkPaintRef;
+pub fn SkiaSkPaintDelete(aPaint: SkiaSkPaintRef);
+pub fn SkiaSkPaintGetColor(aPaint: SkiaSkPaintRef) -> u32;
+pub fn SkiaSkPaintSetColor(aPaint: SkiaSkPaintRef, color: u32);
+pub fn SkiaSkPaintIsAntiAlias(aPaint: SkiaSkPaintRef) -> bool;
+pub fn SkiaSkPaintSetAntiAlias(aPaint: SkiaSkPaintRef, antiAlias: bool);
+pub fn SkiaSkPaintGetStyle(aPaint: SkiaSkPaintRef) -> i32;
+pub fn SkiaSkPaintSetStyle(aPaint: SkiaSkPaintRef, style: i32);
+pub fn SkiaSkPaintSetStroke(aPaint: SkiaSkPaintRef,
+                            width: f32,
+                            cap: i32,
+                            join: i32,
+                            miterLimit: f32,
+                            pathEffect: SkiaSkPathEffectRef);
+
+pub fn SkiaSkSurfaceCreateRaster(width: i32, height: i32) -> SkiaSkSurfaceRef;
+pub fn SkiaSkSurfaceCreateRenderTarget(aContext: SkiaGrContextRef, width: i32, height: i32) -> SkiaSkSurfaceRef;
+pub fn SkiaSkSurfaceRetain(aSurface: SkiaSkSurfaceRef);
+pub fn SkiaSkSurfaceRelease(aSurface: SkiaSkSurfaceRef);
+pub fn SkiaSkSurfaceGetWidth(aSurface: SkiaSkSurfaceRef) -> i32;
+pub fn SkiaSkSurfaceGetHeight(aSurface: SkiaSkSurfaceRef) -> i32;
+pub fn SkiaSkSurfaceGetCanvas(aSurface: SkiaSkSurfaceRef) -> SkiaSkCanvasRef;
+
+pub fn SkiaSkCanvasSave(aCanvas: SkiaSkCanvasRef) -> i32;
+pub fn SkiaSkCanvasRestore(aCanvas: SkiaSkCanvasRef);
+pub fn SkiaSkCanvasRestoreToCount(aCanvas: SkiaSkCanvasRef, saveCount: i32);
+pub fn SkiaSkCanvasTranslate(aCanvas: SkiaSkCanvasRef, dx: f32, dy: f32);
+pub fn SkiaSkCanvasScale(aCanvas: SkiaSkCanvasRef, sx: f32, sy: f32);
+pub fn SkiaSkCanvasRotate(aCanvas: SkiaSkCanvasRef, degrees: f32);
+pub fn SkiaSkCanvasConcat(aCanvas: SkiaSkCanvasRef, matrix: *const SkiaMatrix);
+pub fn SkiaSkCanvasSetMatrix(aCanvas: SkiaSkCanvasRef, matrix: *const SkiaMatrix);
+pub fn SkiaSkCanvasClear(aCanvas: SkiaSkCanvasRef, color: u32);
+pub fn SkiaSkCanvasFlush(aCanvas: SkiaSkCanvasRef);
+pub fn SkiaSkCanvasClipRect(aCanvas: SkiaSkCanvasRef, rect: *const SkiaRect, op: i32, antiAlias: bool);
+pub fn SkiaSkCanvasClipRRect(aCanvas: SkiaSkCanvasRef, rrect: *const SkiaRRect, op: i32, antiAlias: bool);
+pub fn SkiaSkCanvasClipPath(aCanvas: SkiaSkCanvasRef, path: SkiaSkPathRef, op: i32, antiAlias: bool);
+pub fn SkiaSkCanvasClipRegion(aCanvas: SkiaSkCanvasRef, deviceRegion: SkiaSkRegionRef, op: i32);
+pub fn SkiaSkCanvasDrawPaint(aCanvas: SkiaSkCanvasRef, paint: SkiaSkPaintRef);
+pub fn SkiaSkCanvasDrawRect(aCanvas: SkiaSkCanvasRef, rect: *const SkiaRect, paint: SkiaSkPaintRef);
+pub fn SkiaSkCanvasDrawOval(aCanvas: SkiaSkCanvasRef, oval: *const SkiaRect, paint: SkiaSkPaintRef);
+pub fn SkiaSkCanvasDrawRRect(aCanvas: SkiaSkCanvasRef, rrect: *const SkiaRRect, paint: SkiaSkPaintRef);
+pub fn SkiaSkCanvasDrawDRRect(aCanvas: SkiaSkCanvasRef,
+                              outer: *const SkiaRRect,
+                              inner: *const SkiaRRect,
+                              paint: SkiaSkPaintRef);
+pub fn SkiaSkCanvasDrawPath(aCanvas: SkiaSkCanvasRef, path: SkiaSkPathRef, paint: SkiaSkPaintRef);
+pub fn SkiaSkCanvasDrawRegion(aCanvas: SkiaSkCanvasRef, region: SkiaSkRegionRef, paint: SkiaSkPaintRef);
+
+pub fn SkiaSkMatrix44Invert(aMatrix: *const SkiaMatrix44, result: *mut SkiaMatrix44) -> bool;
+pub fn SkiaSkMatrix44Concat(a: *const SkiaMatrix44, b: *const SkiaMatrix44, result: *mut SkiaMatrix44);
+pub fn SkiaSkMatrix44MapScalars(aMatrix: *const SkiaMatrix44, src: *const f32, dst: *mut f32);
+pub fn SkiaSkMatrix44ToSkMatrix(aMatrix: *const SkiaMatrix44, result: *mut SkiaMatrix);
+pub fn SkiaSkMatrix44FromSkMatrix(aMatrix: *const SkiaMatrix, result: *mut SkiaMatrix44);
+pub fn SkiaSkMatrixInvert(aMatrix: *const SkiaMatrix, result: *mut SkiaMatrix) -> bool;
+pub fn SkiaSkMatrixConcat(a: *const SkiaMatrix, b: *const SkiaMatrix, result: *mut SkiaMatrix);
+pub fn SkiaSkMatrixMapPoints(aMatrix: *const SkiaMatrix, points: *mut SkiaPoint, count: i32);
+
+pub fn Skia3DViewCreate() -> Skia3DViewRef;
+pub fn Skia3DViewDelete(aView: Skia3DViewRef);
+pub fn Skia3DViewSave(aView: Skia3DViewRef);
+pub fn Skia3DViewRestore(aView: Skia3DViewRef);
+pub fn Skia3DViewTranslate(aView: Skia3DViewRef, x: f32, y: f32, z: f32);
+pub fn Skia3DViewRotateX(aView: Skia3DViewRef, degrees: f32);
+pub fn Skia3DViewRotateY(aView: Skia3DViewRef, degrees: f32);
+pub fn Skia3DViewRotateZ(aView: Skia3DViewRef, degrees: f32);
+pub fn Skia3DViewGetMatrix(aView: Skia3DViewRef, result: *mut SkiaMatrix);
+pub fn Skia3DViewApplyToCanvas(aView: Skia3DViewRef, canvas: SkiaSkCanvasRef);
+pub fn Skia3DViewDotWithNormal(aView: Skia3DViewRef, dx: f32, dy: f32, dz: f32) -> f32;
+pub fn SkiaSkCamera3DPatchToMatrix(aCamera: *const SkiaCamera3D, patch: *const SkiaPatch3D, result: *mut SkiaMatrix);
+
+pub fn SkiaSkImageCreateFromEncoded(data: *const c_void, length: size_t, format: *mut i32) -> SkiaSkImageRef;
+pub fn SkiaSkImageRetain(aImage: SkiaSkImageRef);
+pub fn SkiaSkImageRelease(aImage: SkiaSkImageRef);
+pub fn SkiaSkImageGetWidth(aImage: SkiaSkImageRef) -> i32;
+pub fn SkiaSkImageGetHeight(aImage: SkiaSkImageRef) -> i32;
+pub fn SkiaSkCanvasDrawImage(aCanvas: SkiaSkCanvasRef, image: SkiaSkImageRef, x: f32, y: f32, paint: SkiaSkPaintRef);
+
+pub fn SkiaSkSurfaceNewImageSnapshot(aSurface: SkiaSkSurfaceRef) -> SkiaSkImageRef;
+pub fn SkiaSkImageEncode(aImage: SkiaSkImageRef, type_: i32, quality: i32, callback: SkiaWriteCallback, closure: *mut c_void) -> bool;
+
+pub fn SkiaSkMovieCreateFromEncoded(data: *const c_void, length: size_t) -> SkiaSkMovieRef;
+pub fn SkiaSkMovieRelease(aMovie: SkiaSkMovieRef);
+pub fn SkiaSkMovieGetDuration(aMovie: SkiaSkMovieRef) -> u32;
+pub fn SkiaSkMovieGetWidth(aMovie: SkiaSkMovieRef) -> i32;
+pub fn SkiaSkMovieGetHeight(aMovie: SkiaSkMovieRef) -> i32;
+pub fn SkiaSkMovieIsOpaque(aMovie: SkiaSkMovieRef) -> bool;
+pub fn SkiaSkMovieSetTime(aMovie: SkiaSkMovieRef, time: u32) -> bool;
+pub fn SkiaSkMovieGetFrame(aMovie: SkiaSkMovieRef) -> SkiaSkImageRef;
+
+pub fn SkiaSkImageCreateLazyFromEncoded(data: *const c_void, length: size_t, format: *mut i32) -> SkiaSkImageRef;
+pub fn SkiaSkDiscardableMemoryPoolGetRAMUsed() -> size_t;
+pub fn SkiaSkDiscardableMemoryPoolGetRAMBudget() -> size_t;
+pub fn SkiaSkDiscardableMemoryPoolSetRAMBudget(budget: size_t);
+pub fn SkiaSkDiscardableMemoryPoolPurge();
+
+pub fn SkiaSkImageCreateFromGenerator(callbacks: *const SkiaImageGeneratorCallbacks, closure: *mut c_void) -> SkiaSkImageRef;
+
+pub fn SkiaSkPaintGetFilterLevel(aPaint: SkiaSkPaintRef) -> i32;
+pub fn SkiaSkPaintSetFilterLevel(aPaint: SkiaSkPaintRef, level: i32);
+pub fn SkiaSkCanvasDrawImageRect(aCanvas: SkiaSkCanvasRef, image: SkiaSkImageRef, src: *const SkiaRect, dst: *const SkiaRect, paint: SkiaSkPaintRef);
+pub fn SkiaSkBitmapScalerResize(srcInfo: *const SkiaImageInfo, srcPixels: *const c_void, srcRowBytes: size_t, method: i32, dstWidth: i32, dstHeight: i32, dstPixels: *mut c_void, dstRowBytes: size_t) -> bool;
+
+pub fn SkiaSkImageCreateFromCompressedTexture(aContext: SkiaGrContextRef, format: i32, width: i32, height: i32, data: *const c_void, length: size_t) -> SkiaSkImageRef;
+pub fn SkiaETC1ContainerGetData(data: *const c_void, length: size_t, width: *mut i32, height: *mut i32, offset: *mut size_t) -> bool;
+pub fn SkiaETC1EncodeImage(info: *const SkiaImageInfo, pixels: *const c_void, rowBytes: size_t, dst: *mut c_void, dstLength: size_t) -> bool;
+pub fn SkiaSkTextureCompressorCompressLATC(info: *const SkiaImageInfo, pixels: *const c_void, rowBytes: size_t, dst: *mut c_void, dstLength: size_t) -> bool;
+
+pub fn SkiaConvertPixels(srcInfo: *const SkiaImageInfo, srcPixels: *const c_void, srcRowBytes: size_t, srcPalette: *const u32, srcPaletteCount: i32, dstInfo: *const SkiaImageInfo, dstPixels: *mut c_void, dstRowBytes: size_t) -> bool;
+
+pub fn SkiaSkDataCreateWithCopy(data: *const c_void, length: size_t) -> SkiaSkDataRef;
+pub fn SkiaSkDataRelease(aData: SkiaSkDataRef);
+pub fn SkiaSkImageCreateIndex8(width: i32, height: i32, indices: SkiaSkDataRef, rowBytes: size_t, palette: *const u32, count: i32) -> SkiaSkImageRef;
+
+pub fn SkiaSkGradientShaderCreateLinear(start: *const SkiaPoint, end: *const SkiaPoint, gradient: *const SkiaGradient) -> SkiaSkShaderRef;
+pub fn SkiaSkGradientShaderCreateRadial(center: *const SkiaPoint, radius: f32, gradient: *const SkiaGradient) -> SkiaSkShaderRef;
+pub fn SkiaSkGradientShaderCreateTwoPointRadial(start: *const SkiaPoint, startRadius: f32, end: *const SkiaPoint, endRadius: f32, gradient: *const SkiaGradient) -> SkiaSkShaderRef;
+pub fn SkiaSkGradientShaderCreateTwoPointConical(start: *const SkiaPoint, startRadius: f32, end: *const SkiaPoint, endRadius: f32, gradient: *const SkiaGradient) -> SkiaSkShaderRef;
+pub fn SkiaSkGradientShaderCreateSweep(center: *const SkiaPoint, gradient: *const SkiaGradient) -> SkiaSkShaderRef;
+pub fn SkiaSkShaderRetain(aShader: SkiaSkShaderRef);
+pub fn SkiaSkShaderRelease(aShader: SkiaSkShaderRef);
+pub fn SkiaSkPaintSetShader(aPaint: SkiaSkPaintRef, shader: SkiaSkShaderRef);
+
+pub fn SkiaSkColorFilterCreateMatrix(matrix: *const f32) -> SkiaSkColorFilterRef;
+pub fn SkiaSkColorFilterRetain(aColorFilter: SkiaSkColorFilterRef);
+pub fn SkiaSkColorFilterRelease(aColorFilter: SkiaSkColorFilterRef);
+pub fn SkiaSkColorFilterCreateTable(tableA: *const u8, tableR: *const u8, tableG: *const u8, tableB: *const u8) -> SkiaSkColorFilterRef;
+pub fn SkiaSkColorFilterCreateLuma() -> SkiaSkColorFilterRef;
+pub fn SkiaSkColorFilterCreateMode(color: u32, mode: i32) -> SkiaSkColorFilterRef;
+pub fn SkiaSkColorFilterCreateLighting(mul: u32, add: u32) -> SkiaSkColorFilterRef;
+pub fn SkiaSkColorFilterCompose(outer: SkiaSkColorFilterRef, inner: SkiaSkColorFilterRef) -> SkiaSkColorFilterRef;
+pub fn SkiaSkPaintSetColorFilter(aPaint: SkiaSkPaintRef, colorFilter: SkiaSkColorFilterRef);
+
+pub fn SkiaSkImageFilterCreateSourceAlpha() -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateBlur(sigmaX: f32, sigmaY: f32, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateDropShadow(dx: f32, dy: f32, sigmaX: f32, sigmaY: f32, color: u32, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateMorphology(dilate: bool, radiusX: i32, radiusY: i32, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateDisplacementMap(xChannel: i32, yChannel: i32, scale: f32, displacement: SkiaSkImageFilterRef, color: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateDiffuseLighting(light: *const SkiaLight, surfaceScale: f32, kd: f32, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateSpecularLighting(light: *const SkiaLight, surfaceScale: f32, ks: f32, shininess: f32, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateMatrixConvolution(width: i32, height: i32, kernel: *const f32, gain: f32, bias: f32, offsetX: i32, offsetY: i32, tileMode: i32, convolveAlpha: bool, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateColorFilter(colorFilter: SkiaSkColorFilterRef, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateMerge(inputs: *const SkiaSkImageFilterRef, modes: *const i32, count: i32, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateCompose(outer: SkiaSkImageFilterRef, inner: SkiaSkImageFilterRef) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateOffset(dx: f32, dy: f32, input: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateTile(src: *const SkiaRect, dst: *const SkiaRect, input: SkiaSkImageFilterRef) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterCreateBlend(mode: i32, background: SkiaSkImageFilterRef, foreground: SkiaSkImageFilterRef, cropRect: *const SkiaCropRect) -> SkiaSkImageFilterRef;
+pub fn SkiaSkImageFilterRetain(aImageFilter: SkiaSkImageFilterRef);
+pub fn SkiaSkImageFilterRelease(aImageFilter: SkiaSkImageFilterRef);
+pub fn SkiaSkPaintSetImageFilter(aPaint: SkiaSkPaintRef, imageFilter: SkiaSkImageFilterRef);
+pub fn SkiaSkCanvasSaveLayer(aCanvas: SkiaSkCanvasRef, bounds: *const SkiaRect, paint: SkiaSkPaintRef) -> i32;
+
+pub fn SkiaSkMaskFilterCreateBlur(style: i32, sigma: f32, flags: u32) -> SkiaSkMaskFilterRef;
+pub fn SkiaSkMaskFilterCreateEmboss(sigma: f32, direction: *const f32, ambient: f32, specular: f32) -> SkiaSkMaskFilterRef;
+pub fn SkiaSkMaskFilterCreateTable(table: *const u8) -> SkiaSkMaskFilterRef;
+pub fn SkiaSkMaskFilterCreateGamma(gamma: f32) -> SkiaSkMaskFilterRef;
+pub fn SkiaSkMaskFilterCreateClip(min: u8, max: u8) -> SkiaSkMaskFilterRef;
+pub fn SkiaSkMaskFilterCreateStipple() -> SkiaSkMaskFilterRef;
+pub fn SkiaSkMaskFilterRetain(aMaskFilter: SkiaSkMaskFilterRef);
+pub fn SkiaSkMaskFilterRelease(aMaskFilter: SkiaSkMaskFilterRef);
+pub fn SkiaSkPaintSetMaskFilter(aPaint: SkiaSkPaintRef, maskFilter: SkiaSkMaskFilterRef);
+
+pub fn SkiaSkXfermodeCreateArithmetic(k1: f32, k2: f32, k3: f32, k4: f32, enforcePremul: bool) -> SkiaSkXfermodeRef;
+pub fn SkiaSkXfermodeCreateLerp(scale: f32) -> SkiaSkXfermodeRef;
+pub fn SkiaSkXfermodeCreateAvoid(color: u32, tolerance: u8, mode: i32) -> SkiaSkXfermodeRef;
+pub fn SkiaSkXfermodeCreatePixelXor(color: u32) -> SkiaSkXfermodeRef;
+pub fn SkiaSkXfermodeRetain(aXfermode: SkiaSkXfermodeRef);
+pub fn SkiaSkXfermodeRelease(aXfermode: SkiaSkXfermodeRef);
+pub fn SkiaSkXfermodeIsSupportedOnGpu(aXfermode: SkiaSkXfermodeRef) -> bool;
+pub fn SkiaSkPaintSetXfermode(aPaint: SkiaSkPaintRef, xfermode: SkiaSkXfermodeRef);
+pub fn SkiaSkPaintSetXfermodeMode(aPaint: SkiaSkPaintRef, mode: i32);
+pub fn SkiaSkImageFilterCreateXfermode(xfermode: SkiaSkXfermodeRef,
+                                       background: SkiaSkImageFilterRef,
+                                       foreground: SkiaSkImageFilterRef,
+                                       cropRect: *const SkiaCropRect)
+                                       -> SkiaSkImageFilterRef;
+
+pub fn SkiaSkLayerDrawLooperBuilderCreate() -> SkiaSkLayerDrawLooperBuilderRef;
+pub fn SkiaSkLayerDrawLooperBuilderDelete(aBuilder: SkiaSkLayerDrawLooperBuilderRef);
+pub fn SkiaSkLayerDrawLooperBuilderAddLayer(aBuilder: SkiaSkLayerDrawLooperBuilderRef,
+                                            info: *const SkiaLayerInfo,
+                                            paint: SkiaSkPaintRef)
+                                            -> bool;
+pub fn SkiaSkLayerDrawLooperBuilderAddLayerOnTop(aBuilder: SkiaSkLayerDrawLooperBuilderRef,
+                                                 info: *const SkiaLayerInfo,
+                                                 paint: SkiaSkPaintRef)
+                                                 -> bool;
+pub fn SkiaSkLayerDrawLooperBuilderDetachLooper(aBuilder: SkiaSkLayerDrawLooperBuilderRef)
+                                                -> SkiaSkDrawLooperRef;
+pub fn SkiaSkBlurDrawLooperCreate(color: u32, sigma: f32, dx: f32, dy: f32, flags: u32) -> SkiaSkDrawLooperRef;
+pub fn SkiaSkDrawLooperRetain(aLooper: SkiaSkDrawLooperRef);
+pub fn SkiaSkDrawLooperRelease(aLooper: SkiaSkDrawLooperRef);
+pub fn SkiaSkPaintSetLooper(aPaint: SkiaSkPaintRef, looper: SkiaSkDrawLooperRef);
+
+pub fn SkiaSkPictureRecorderCreate() -> SkiaSkPictureRecorderRef;
+pub fn SkiaSkPictureRecorderDelete(aRecorder: SkiaSkPictureRecorderRef);
+pub fn SkiaSkPictureRecorderBeginRecording(aRecorder: SkiaSkPictureRecorderRef,
+                                           width: i32,
+                                           height: i32)
+                                           -> SkiaSkCanvasRef;
+pub fn SkiaSkPictureRecorderEndRecording(aRecorder: SkiaSkPictureRecorderRef) -> SkiaSkPictureRef;
+pub fn SkiaSkPictureRetain(aPicture: SkiaSkPictureRef);
+pub fn SkiaSkPictureRelease(aPicture: SkiaSkPictureRef);
+pub fn SkiaSkPictureGetWidth(aPicture: SkiaSkPictureRef) -> i32;
+pub fn SkiaSkPictureGetHeight(aPicture: SkiaSkPictureRef) -> i32;
+pub fn SkiaSkCanvasDrawPicture(aCanvas: SkiaSkCanvasRef, picture: SkiaSkPictureRef);
+
+pub fn SkiaSkShaderCreateImage(image: SkiaSkImageRef,
+                               tileModeX: i32,
+                               tileModeY: i32,
+                               localMatrix: *const SkiaMatrix)
+                               -> SkiaSkShaderRef;
+pub fn SkiaSkShaderCreatePicture(picture: SkiaSkPictureRef,
+                                 tileModeX: i32,
+                                 tileModeY: i32,
+                                 localMatrix: *const SkiaMatrix)
+                                 -> SkiaSkShaderRef;
+pub fn SkiaSkShaderCreatePerlinNoise(turbulence: bool,
+                                     baseFrequencyX: f32,
+                                     baseFrequencyY: f32,
+                                     octaves: i32,
+                                     seed: f32,
+                                     tileWidth: i32,
+                                     tileHeight: i32)
+                                     -> SkiaSkShaderRef;
+pub fn SkiaSkShaderCreateCompose(dst: SkiaSkShaderRef, src: SkiaSkShaderRef, mode: i32) -> SkiaSkShaderRef;
+pub fn SkiaSkShaderCreateLocalMatrix(aShader: SkiaSkShaderRef, localMatrix: *const SkiaMatrix) -> SkiaSkShaderRef;
+
+pub fn SkiaSkTypefaceCreateFromFile(path: *const c_char, ttcIndex: i32) -> SkiaSkTypefaceRef;
+pub fn SkiaSkTypefaceCreateFromData(data: *const c_void, length: size_t, ttcIndex: i32) -> SkiaSkTypefaceRef;
+pub fn SkiaSkTypefaceCreateFromName(familyName: *const c_char, style: *const SkiaFontStyle) -> SkiaSkTypefaceRef;
+pub fn SkiaSkTypefaceRetain(aTypeface: SkiaSkTypefaceRef);
+pub fn SkiaSkTypefaceRelease(aTypeface: SkiaSkTypefaceRef);
+pub fn SkiaSkTypefaceGetFamilyName(aTypeface: SkiaSkTypefaceRef, buffer: *mut c_char, capacity: i32) -> i32;
+pub fn SkiaSkTypefaceGetStyle(aTypeface: SkiaSkTypefaceRef, style: *mut SkiaFontStyle);
+pub fn SkiaSkTypefaceGetUnitsPerEm(aTypeface: SkiaSkTypefaceRef) -> i32;
+pub fn SkiaSkTypefaceCountGlyphs(aTypeface: SkiaSkTypefaceRef) -> i32;
+
+pub fn SkiaSkFontMgrRefDefault() -> SkiaSkFontMgrRef;
+pub fn SkiaSkFontMgrRetain(aFontMgr: SkiaSkFontMgrRef);
+pub fn SkiaSkFontMgrRelease(aFontMgr: SkiaSkFontMgrRef);
+pub fn SkiaSkFontMgrCountFamilies(aFontMgr: SkiaSkFontMgrRef) -> i32;
+pub fn SkiaSkFontMgrGetFamilyName(aFontMgr: SkiaSkFontMgrRef, index: i32, buffer: *mut c_char, capacity: i32) -> i32;
+pub fn SkiaSkFontMgrCreateStyleSet(aFontMgr: SkiaSkFontMgrRef, index: i32) -> SkiaSkFontStyleSetRef;
+pub fn SkiaSkFontMgrMatchFamily(aFontMgr: SkiaSkFontMgrRef, familyName: *const c_char) -> SkiaSkFontStyleSetRef;
+pub fn SkiaSkFontMgrMatchFamilyStyle(aFontMgr: SkiaSkFontMgrRef, familyName: *const c_char, style: *const SkiaFontStyle) -> SkiaSkTypefaceRef;
+pub fn SkiaSkFontMgrMatchFamilyStyleCharacter(aFontMgr: SkiaSkFontMgrRef, familyName: *const c_char, style: *const SkiaFontStyle, bcp47: *const c_char, character: u32) -> SkiaSkTypefaceRef;
+pub fn SkiaSkFontMgrMatchFaceStyle(aFontMgr: SkiaSkFontMgrRef, typeface: SkiaSkTypefaceRef, style: *const SkiaFontStyle) -> SkiaSkTypefaceRef;
+
+pub fn SkiaSkFontStyleSetRetain(aStyleSet: SkiaSkFontStyleSetRef);
+pub fn SkiaSkFontStyleSetRelease(aStyleSet: SkiaSkFontStyleSetRef);
+pub fn SkiaSkFontStyleSetCount(aStyleSet: SkiaSkFontStyleSetRef) -> i32;
+pub fn SkiaSkFontStyleSetGetStyle(aStyleSet: SkiaSkFontStyleSetRef, index: i32, style: *mut SkiaFontStyle, nameBuffer: *mut c_char, nameCapacity: i32) -> i32;
+pub fn SkiaSkFontStyleSetCreateTypeface(aStyleSet: SkiaSkFontStyleSetRef, index: i32) -> SkiaSkTypefaceRef;
+pub fn SkiaSkFontStyleSetMatchStyle(aStyleSet: SkiaSkFontStyleSetRef, style: *const SkiaFontStyle) -> SkiaSkTypefaceRef;
+
+pub fn SkiaSkFontConfigInterfaceSetGlobal(callbacks: *const SkiaFontConfigCallbacks, closure: *mut c_void);
+pub fn SkiaFontMatchSet(result: SkiaFontMatchRef, identity: *const SkiaFontIdentity, familyName: *const c_char, familyNameLength: size_t);
+
 }
@@ -0,0 +1,59 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use path_effect::PathEffect;
+use skia;
+
+use std::ptr;
+
+/// How the ends of open contours are drawn, matching `SkPaint::Cap`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+/// How corners between segments are drawn, matching `SkPaint::Join`.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    Miter = 0,
+    Round = 1,
+    Bevel = 2,
+}
+
+/// The parameters used to turn a path into its stroke outline. A width of
+/// zero is a hairline, which has no outline.
+#[derive(Clone)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub cap: LineCap,
+    pub join: LineJoin,
+    pub miter_limit: f32,
+    /// Applied to the path before stroking, e.g. `PathEffect::dash()`.
+    pub path_effect: Option<PathEffect>,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> StrokeStyle {
+        StrokeStyle {
+            width: 1.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+            path_effect: None,
+        }
+    }
+}
+
+impl StrokeStyle {
+    pub fn path_effect_as_raw(&self) -> skia::SkiaSkPathEffectRef {
+        match self.path_effect {
+            Some(ref path_effect) => path_effect.as_raw(),
+            None => ptr::null_mut(),
+        }
+    }
+}
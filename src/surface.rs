@@ -0,0 +1,95 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use canvas::Canvas;
+use gl_context::GLContext;
+use image::{EncodedFormat, Image};
+use skia;
+
+use euclid::default::Size2D;
+use std::io::{self, Write};
+
+/// Pixels that can be drawn into through a `Canvas`, either in memory or in
+/// a GPU render target.
+pub struct Surface {
+    surface: skia::SkiaSkSurfaceRef,
+}
+
+impl Drop for Surface {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkSurfaceRelease(self.surface);
+        }
+    }
+}
+
+impl Surface {
+    fn from_raw(surface: skia::SkiaSkSurfaceRef) -> Option<Surface> {
+        if surface.is_null() {
+            return None;
+        }
+        Some(Surface { surface })
+    }
+
+    /// A premultiplied N32 surface backed by memory.
+    pub fn new_raster(size: Size2D<i32>) -> Option<Surface> {
+        Surface::from_raw(unsafe { skia::SkiaSkSurfaceCreateRaster(size.width, size.height) })
+    }
+
+    /// A premultiplied N32 surface backed by a render target in the GPU
+    /// context of `gl_context`, which must be current whenever the surface
+    /// is drawn to.
+    pub fn new_render_target(gl_context: &GLContext, size: Size2D<i32>) -> Option<Surface> {
+        gl_context.make_current();
+        Surface::from_raw(unsafe {
+            skia::SkiaSkSurfaceCreateRenderTarget(gl_context.gr_context, size.width, size.height)
+        })
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkSurfaceRef {
+        self.surface
+    }
+
+    pub fn size(&self) -> Size2D<i32> {
+        unsafe {
+            Size2D::new(skia::SkiaSkSurfaceGetWidth(self.surface),
+                        skia::SkiaSkSurfaceGetHeight(self.surface))
+        }
+    }
+
+    /// The canvas that draws into this surface. It is owned by the surface,
+    /// so it keeps its matrix and clip between calls.
+    pub fn canvas(&mut self) -> Canvas<'_> {
+        unsafe {
+            Canvas::from_raw(skia::SkiaSkSurfaceGetCanvas(self.surface))
+        }
+    }
+
+    /// An immutable copy of the surface's current contents. Skia defers the
+    /// copy until the surface is next drawn to.
+    pub fn image_snapshot(&self) -> Option<Image> {
+        let image = unsafe { skia::SkiaSkSurfaceNewImageSnapshot(self.surface) };
+        if image.is_null() {
+            return None;
+        }
+        Some(unsafe { Image::from_raw(image) })
+    }
+
+    /// Encodes the surface's current contents; see `Image::encode()`.
+    pub fn encode<W: Write>(&self,
+                            format: EncodedFormat,
+                            quality: u8,
+                            writer: &mut W)
+                            -> io::Result<()> {
+        let image = self.image_snapshot()
+                        .ok_or_else(|| io::Error::other("could not snapshot surface"))?;
+        image.encode(format, quality, writer)
+    }
+
+    pub fn encode_to_vec(&self, format: EncodedFormat, quality: u8) -> io::Result<Vec<u8>> {
+        let mut result = Vec::new();
+        self.encode(format, quality, &mut result)?;
+        Ok(result)
+    }
+}
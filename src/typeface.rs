@@ -0,0 +1,451 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use skia;
+
+use libc::{c_char, c_void, size_t};
+use std::ffi::CString;
+use std::ops::Range;
+use std::path::Path;
+use std::ptr;
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FontSlant {
+    Upright = 0,
+    /// Italic or oblique.
+    Italic = 1,
+}
+
+/// The weight, width and slant of a face, matching `SkFontStyle` and the
+/// CSS `font-weight`, `font-stretch` and `font-style` properties.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FontStyle {
+    /// From 100 to 900, as in CSS.
+    pub weight: i32,
+    /// From 1, ultra-condensed, to 9, ultra-expanded.
+    pub width: i32,
+    pub slant: FontSlant,
+}
+
+impl Default for FontStyle {
+    fn default() -> FontStyle {
+        FontStyle::new(FontStyle::NORMAL_WEIGHT, FontStyle::NORMAL_WIDTH, FontSlant::Upright)
+    }
+}
+
+impl FontStyle {
+    pub const NORMAL_WEIGHT: i32 = 400;
+    pub const BOLD_WEIGHT: i32 = 700;
+    pub const CONDENSED_WIDTH: i32 = 3;
+    pub const NORMAL_WIDTH: i32 = 5;
+    pub const EXPANDED_WIDTH: i32 = 7;
+
+    pub fn new(weight: i32, width: i32, slant: FontSlant) -> FontStyle {
+        FontStyle {
+            weight,
+            width,
+            slant,
+        }
+    }
+
+    pub fn to_raw(self) -> skia::SkiaFontStyle {
+        skia::SkiaFontStyle {
+            weight: self.weight,
+            width: self.width,
+            slant: self.slant as i32,
+        }
+    }
+
+    pub fn from_raw(style: skia::SkiaFontStyle) -> FontStyle {
+        let slant = if style.slant == FontSlant::Italic as i32 {
+            FontSlant::Italic
+        } else {
+            FontSlant::Upright
+        };
+        FontStyle::new(style.weight, style.width, slant)
+    }
+}
+
+/// A reference counted `SkTypeface`: one face of a font, which text is shaped
+/// and drawn with.
+pub struct Typeface {
+    typeface: skia::SkiaSkTypefaceRef,
+}
+
+impl Drop for Typeface {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkTypefaceRelease(self.typeface);
+        }
+    }
+}
+
+impl Clone for Typeface {
+    fn clone(&self) -> Typeface {
+        unsafe {
+            skia::SkiaSkTypefaceRetain(self.typeface);
+        }
+        Typeface {
+            typeface: self.typeface,
+        }
+    }
+}
+
+// Typefaces are immutable once created and SkRefCnt is thread-safe.
+unsafe impl Send for Typeface {}
+unsafe impl Sync for Typeface {}
+
+impl Typeface {
+    fn from_raw(typeface: skia::SkiaSkTypefaceRef) -> Option<Typeface> {
+        if typeface.is_null() {
+            return None;
+        }
+        Some(Typeface { typeface })
+    }
+
+    /// Loads the face at `ttc_index` in a font file. The index is 0 except
+    /// in TrueType collections. Returns `None` if the file cannot be read or
+    /// has no such face, or the path is not UTF-8.
+    pub fn from_file(path: &Path, ttc_index: i32) -> Option<Typeface> {
+        let path = CString::new(path.to_str()?).ok()?;
+        unsafe { Typeface::from_raw(skia::SkiaSkTypefaceCreateFromFile(path.as_ptr(), ttc_index)) }
+    }
+
+    /// Copies font data held in memory, such as a downloaded web font, and
+    /// loads the face at `ttc_index`.
+    pub fn from_data(data: &[u8], ttc_index: i32) -> Option<Typeface> {
+        unsafe {
+            Typeface::from_raw(skia::SkiaSkTypefaceCreateFromData(data.as_ptr() as *const c_void,
+                                                                  data.len() as size_t,
+                                                                  ttc_index))
+        }
+    }
+
+    /// Returns the installed face of `family_name` closest to `style`. If
+    /// the family is not installed, this falls back on the system's default
+    /// font, so compare `family_name()` to tell. Returns `None` if the style
+    /// is out of range.
+    pub fn from_family(family_name: &str, style: &FontStyle) -> Option<Typeface> {
+        let family_name = CString::new(family_name).ok()?;
+        let style = style.to_raw();
+        unsafe {
+            Typeface::from_raw(skia::SkiaSkTypefaceCreateFromName(family_name.as_ptr(), &style))
+        }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkTypefaceRef {
+        self.typeface
+    }
+
+    /// The family name as given by the font, or the one it was matched by.
+    pub fn family_name(&self) -> String {
+        read_string(|buffer, capacity| unsafe {
+            skia::SkiaSkTypefaceGetFamilyName(self.typeface, buffer, capacity)
+        })
+    }
+
+    /// The style, from the font's OS/2 table where it has one and otherwise
+    /// from its bold and italic flags.
+    pub fn style(&self) -> FontStyle {
+        let mut style = skia::SkiaFontStyle::default();
+        unsafe {
+            skia::SkiaSkTypefaceGetStyle(self.typeface, &mut style);
+        }
+        FontStyle::from_raw(style)
+    }
+
+    /// The size of the em square in font units, or 0 if it is unknown.
+    pub fn units_per_em(&self) -> i32 {
+        unsafe { skia::SkiaSkTypefaceGetUnitsPerEm(self.typeface) }
+    }
+
+    pub fn glyph_count(&self) -> i32 {
+        unsafe { skia::SkiaSkTypefaceCountGlyphs(self.typeface) }
+    }
+}
+
+/// A reference counted `SkFontMgr`, which lists the fonts installed on the
+/// system and picks among them.
+pub struct FontMgr {
+    font_mgr: skia::SkiaSkFontMgrRef,
+}
+
+impl Drop for FontMgr {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkFontMgrRelease(self.font_mgr);
+        }
+    }
+}
+
+impl Clone for FontMgr {
+    fn clone(&self) -> FontMgr {
+        unsafe {
+            skia::SkiaSkFontMgrRetain(self.font_mgr);
+        }
+        FontMgr {
+            font_mgr: self.font_mgr,
+        }
+    }
+}
+
+// Font managers only have const methods, which lock around the platform's
+// font library where it needs it, and SkRefCnt is thread-safe.
+unsafe impl Send for FontMgr {}
+unsafe impl Sync for FontMgr {}
+
+impl Default for FontMgr {
+    fn default() -> FontMgr {
+        FontMgr::new()
+    }
+}
+
+impl FontMgr {
+    /// The platform's font manager, which the whole process shares.
+    pub fn new() -> FontMgr {
+        let font_mgr = unsafe { skia::SkiaSkFontMgrRefDefault() };
+        assert!(!font_mgr.is_null(), "SkFontMgr::RefDefault failed");
+        FontMgr { font_mgr }
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkFontMgrRef {
+        self.font_mgr
+    }
+
+    pub fn family_count(&self) -> usize {
+        unsafe { skia::SkiaSkFontMgrCountFamilies(self.font_mgr).max(0) as usize }
+    }
+
+    pub fn family_name(&self, index: usize) -> Option<String> {
+        if index >= self.family_count() {
+            return None;
+        }
+        Some(read_string(|buffer, capacity| unsafe {
+            skia::SkiaSkFontMgrGetFamilyName(self.font_mgr, index as i32, buffer, capacity)
+        }))
+    }
+
+    /// The names of the installed families, for listing in a font picker.
+    pub fn family_names(&self) -> FamilyNames<'_> {
+        FamilyNames {
+            font_mgr: self,
+            indices: 0..self.family_count(),
+        }
+    }
+
+    /// The faces of the family at `index` in `family_names()`.
+    pub fn style_set(&self, index: usize) -> Option<FontStyleSet> {
+        if index >= self.family_count() {
+            return None;
+        }
+        FontStyleSet::from_raw(unsafe {
+            skia::SkiaSkFontMgrCreateStyleSet(self.font_mgr, index as i32)
+        })
+    }
+
+    /// The faces of a family by name, which may include hidden fonts that
+    /// `family_names()` leaves out. The set is empty if no font has the
+    /// name, which is `None` only if it contains a NUL.
+    pub fn match_family(&self, family_name: &str) -> Option<FontStyleSet> {
+        let family_name = CString::new(family_name).ok()?;
+        FontStyleSet::from_raw(unsafe {
+            skia::SkiaSkFontMgrMatchFamily(self.font_mgr, family_name.as_ptr())
+        })
+    }
+
+    /// Like `Typeface::from_family`: falls back on the system's default font
+    /// if the family is not installed.
+    pub fn match_family_style(&self, family_name: &str, style: &FontStyle) -> Option<Typeface> {
+        let family_name = CString::new(family_name).ok()?;
+        let style = style.to_raw();
+        Typeface::from_raw(unsafe {
+            skia::SkiaSkFontMgrMatchFamilyStyle(self.font_mgr, family_name.as_ptr(), &style)
+        })
+    }
+
+    /// Finds a fallback face with a glyph for `character`, preferring
+    /// `family_name`, faces close to `style` and those for the BCP 47
+    /// language tag `language`, such as `"ja"`. Returns `None` if no
+    /// installed font covers the character.
+    pub fn match_family_style_character(&self,
+                                        family_name: Option<&str>,
+                                        style: &FontStyle,
+                                        language: Option<&str>,
+                                        character: char)
+                                        -> Option<Typeface> {
+        let family_name = match family_name {
+            Some(family_name) => Some(CString::new(family_name).ok()?),
+            None => None,
+        };
+        let language = match language {
+            Some(language) => Some(CString::new(language).ok()?),
+            None => None,
+        };
+        let style = style.to_raw();
+        Typeface::from_raw(unsafe {
+            skia::SkiaSkFontMgrMatchFamilyStyleCharacter(
+                self.font_mgr,
+                family_name.as_ref().map_or(ptr::null(), |name| name.as_ptr()),
+                &style,
+                language.as_ref().map_or(ptr::null(), |language| language.as_ptr()),
+                character as u32)
+        })
+    }
+
+    /// The face of `typeface`'s family closest to `style`, such as its bold
+    /// for synthesising `<b>` without naming the family again.
+    pub fn match_face_style(&self, typeface: &Typeface, style: &FontStyle) -> Option<Typeface> {
+        let style = style.to_raw();
+        Typeface::from_raw(unsafe {
+            skia::SkiaSkFontMgrMatchFaceStyle(self.font_mgr, typeface.as_raw(), &style)
+        })
+    }
+}
+
+/// An iterator over the installed family names, from `FontMgr::family_names`.
+pub struct FamilyNames<'a> {
+    font_mgr: &'a FontMgr,
+    indices: Range<usize>,
+}
+
+impl<'a> Iterator for FamilyNames<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let index = self.indices.next()?;
+        self.font_mgr.family_name(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for FamilyNames<'a> {}
+
+/// A reference counted `SkFontStyleSet`: the faces of one family.
+pub struct FontStyleSet {
+    style_set: skia::SkiaSkFontStyleSetRef,
+}
+
+impl Drop for FontStyleSet {
+    fn drop(&mut self) {
+        unsafe {
+            skia::SkiaSkFontStyleSetRelease(self.style_set);
+        }
+    }
+}
+
+impl Clone for FontStyleSet {
+    fn clone(&self) -> FontStyleSet {
+        unsafe {
+            skia::SkiaSkFontStyleSetRetain(self.style_set);
+        }
+        FontStyleSet {
+            style_set: self.style_set,
+        }
+    }
+}
+
+// SkRefCnt is thread-safe, but SkFontStyleSet's methods are not const and
+// some implementations fill caches in them, so sets are not Sync.
+unsafe impl Send for FontStyleSet {}
+
+impl FontStyleSet {
+    fn from_raw(style_set: skia::SkiaSkFontStyleSetRef) -> Option<FontStyleSet> {
+        if style_set.is_null() {
+            return None;
+        }
+        Some(FontStyleSet { style_set })
+    }
+
+    pub fn as_raw(&self) -> skia::SkiaSkFontStyleSetRef {
+        self.style_set
+    }
+
+    pub fn len(&self) -> usize {
+        unsafe { skia::SkiaSkFontStyleSetCount(self.style_set).max(0) as usize }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The style of the face at `index` and its name, such as "Bold Italic".
+    pub fn style(&self, index: usize) -> Option<(FontStyle, String)> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut style = skia::SkiaFontStyle::default();
+        let name = read_string(|buffer, capacity| unsafe {
+            skia::SkiaSkFontStyleSetGetStyle(self.style_set,
+                                             index as i32,
+                                             &mut style,
+                                             buffer,
+                                             capacity)
+        });
+        Some((FontStyle::from_raw(style), name))
+    }
+
+    /// The styles of the faces in order, so that the position of one is its
+    /// index for `typeface`.
+    pub fn styles(&self) -> Styles<'_> {
+        Styles {
+            style_set: self,
+            indices: 0..self.len(),
+        }
+    }
+
+    pub fn typeface(&self, index: usize) -> Option<Typeface> {
+        if index >= self.len() {
+            return None;
+        }
+        Typeface::from_raw(unsafe {
+            skia::SkiaSkFontStyleSetCreateTypeface(self.style_set, index as i32)
+        })
+    }
+
+    /// The face closest to `style`, or `None` if the set is empty.
+    pub fn match_style(&self, style: &FontStyle) -> Option<Typeface> {
+        let style = style.to_raw();
+        Typeface::from_raw(unsafe { skia::SkiaSkFontStyleSetMatchStyle(self.style_set, &style) })
+    }
+}
+
+/// An iterator over the styles in a set, from `FontStyleSet::styles`.
+pub struct Styles<'a> {
+    style_set: &'a FontStyleSet,
+    indices: Range<usize>,
+}
+
+impl<'a> Iterator for Styles<'a> {
+    type Item = (FontStyle, String);
+
+    fn next(&mut self) -> Option<(FontStyle, String)> {
+        let index = self.indices.next()?;
+        self.style_set.style(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Styles<'a> {}
+
+/// Calls `read`, which copies up to `capacity` bytes of a UTF-8 string into
+/// `buffer` and returns its full length, until the whole string fits.
+fn read_string<F>(mut read: F) -> String where F: FnMut(*mut c_char, i32) -> i32 {
+    let mut buffer: Vec<u8> = Vec::new();
+    loop {
+        let length = read(buffer.as_mut_ptr() as *mut c_char, buffer.len() as i32).max(0);
+        if length as usize <= buffer.len() {
+            buffer.truncate(length as usize);
+            return String::from_utf8_lossy(&buffer).into_owned();
+        }
+        buffer.resize(length as usize, 0);
+    }
+}
+